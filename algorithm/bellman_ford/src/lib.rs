@@ -1,111 +1,219 @@
 //! # Bellman Ford algorithm
-//! 
-//! 
-const INF: i64 = std::i64::MAX >> 1;
-/// Bellman Ford
-pub fn bellman_ford(n: usize, source: usize, e: &[(usize, usize, i64)], is_direct: bool) -> Vec<i64> {
-    let mut dist = vec![INF; n];
-    dist[source] = 0;
-    if is_direct {
-        loop {
-            let mut update = false;
-            for &(v, w, a) in e.iter() {
-                if dist[v] < INF / 2 && dist[v] + a < dist[w] {
-                    dist[w] = dist[v] + a;
-                    update = true;
-                }
-            }
-            if !update {
-                break dist;
-            }
+//!
+//! Define a struct [`BellmanFord`] which is the result of [`bellman_ford`] and [`spfa`].
+//!
+use std::collections::VecDeque;
+use graph::{Graph, ShortestPathTree, Weight};
+/// struct of the result of Bellman Ford
+#[derive(Clone, Debug)]
+pub struct BellmanFord<W> {
+    /// distance from the source. `W::inf()` if unreachable, meaningless if `negative_infinity[v]`.
+    pub dist: Vec<W>,
+    /// `negative_infinity[v]` is true if the distance to `v` is `-inf`,
+    /// i.e. `v` is reachable from a negative cycle reachable from the source.
+    pub negative_infinity: Vec<bool>,
+}
+impl<W: Weight> BellmanFord<W> {
+    /// Return the distance to `v`. `None` if the distance is `-inf`.
+    pub fn get(&self, v: usize) -> Option<W> {
+        if self.negative_infinity[v] {
+            None
+        } else {
+            Some(self.dist[v])
         }
-    } else {
-        loop {
-            let mut update = false;
-            for &(v, w, a) in e.iter() {
-                if dist[v] < INF / 2 && dist[v] + a < dist[w] {
-                    dist[w] = dist[v] + a;
-                    update = true;
-                }
-                if dist[w] < INF / 2 && dist[w] + a < dist[v] {
-                    dist[v] = dist[w] + a;
-                    update = true;
-                }
+    }
+
+    /// Return if a negative cycle is reachable from the source.
+    pub fn has_negative_cycle(&self) -> bool {
+        self.negative_infinity.iter().any(|&b| b)
+    }
+}
+
+/// Bellman Ford. O(nm)
+pub fn bellman_ford<W: Weight>(graph: &Graph<W>, source: usize) -> BellmanFord<W> {
+    let n = graph.n();
+    let mut dist = vec![W::inf(); n];
+    dist[source] = W::zero();
+    for _ in 0..n {
+        let mut update = false;
+        for e in graph.arcs().iter() {
+            if dist[e.from] < W::inf() && dist[e.from].saturating_add(e.weight) < dist[e.to] {
+                dist[e.to] = dist[e.from].saturating_add(e.weight);
+                update = true;
             }
-            if !update {
-                break dist;
+        }
+        if !update {
+            return BellmanFord { dist, negative_infinity: vec![false; n] };
+        }
+    }
+    // Every vertex still relaxed after n rounds, and all vertices reachable from it, are `-inf`.
+    let mut negative_infinity = vec![false; n];
+    for _ in 0..n {
+        for e in graph.arcs().iter() {
+            if dist[e.from] < W::inf()
+                && (negative_infinity[e.from] || dist[e.from].saturating_add(e.weight) < dist[e.to]) {
+                dist[e.to] = dist[e.from].saturating_add(e.weight).min(dist[e.to]);
+                negative_infinity[e.to] = true;
             }
         }
     }
+    BellmanFord { dist, negative_infinity }
 }
 
-pub fn bellman_ford_re(n: usize, source: usize, e: &[(usize, usize, i64)], is_direct: bool) -> Vec<(i64, Option<usize>)> {
-    let mut dist = vec![(INF, None); n];
-    dist[source] = (0, Some(source));
-    if is_direct {
-        loop {
-            let mut update = false;
-            for &(v, w, a) in e.iter() {
-                if dist[v].0 < INF / 2 && dist[v].0 + a < dist[w].0 {
-                    dist[w] = (dist[v].0 + a, Some(v));
-                    update = true;
-                }
-            }
-            if !update {
-                break dist;
-            }
+/// Shortest Path Faster Algorithm, the queue-based Bellman Ford. O(nm) but usually faster.
+/// The result is the same as [`bellman_ford`].
+pub fn spfa<W: Weight>(graph: &Graph<W>, source: usize) -> BellmanFord<W> {
+    let n = graph.n();
+    let mut dist = vec![W::inf(); n];
+    let mut negative_infinity = vec![false; n];
+    // the number of arcs of the path giving `dist`
+    let mut length = vec![0; n];
+    let mut is_queued = vec![false; n];
+    let mut queue = VecDeque::new();
+    dist[source] = W::zero();
+    queue.push_back(source);
+    is_queued[source] = true;
+    while let Some(v) = queue.pop_front() {
+        is_queued[v] = false;
+        if negative_infinity[v] {
+            continue;
         }
-    } else {
-        loop {
-            let mut update = false;
-            for &(v, w, a) in e.iter() {
-                if dist[v].0 < INF / 2 && dist[v].0 + a < dist[w].0 {
-                    dist[w] = (dist[v].0 + a, Some(w));
-                    update = true;
-                }
-                if dist[w].0 < INF / 2 && dist[w].0 + a < dist[v].0 {
-                    dist[v] = (dist[w].0 + a, Some(w));
-                    update = true;
-                }
+        for e in graph[v].iter() {
+            let d = dist[v].saturating_add(e.weight);
+            if negative_infinity[e.to] || d >= dist[e.to] {
+                continue;
             }
-            if !update {
-                break dist;
+            dist[e.to] = d;
+            length[e.to] = length[v] + 1;
+            if length[e.to] >= n {
+                // The path contains a negative cycle.
+                let mut stack = vec![e.to];
+                negative_infinity[e.to] = true;
+                while let Some(u) = stack.pop() {
+                    for e in graph[u].iter() {
+                        if !negative_infinity[e.to] {
+                            negative_infinity[e.to] = true;
+                            stack.push(e.to);
+                        }
+                    }
+                }
+            } else if !is_queued[e.to] {
+                queue.push_back(e.to);
+                is_queued[e.to] = true;
             }
         }
     }
+    BellmanFord { dist, negative_infinity }
 }
 
-pub fn has_negative_cycle(n: usize, source: usize, e: &[(usize, usize, i64)], is_direct: bool) -> bool {
-    let mut dist = vec![INF; n];
-    dist[source] = 0;
-    if is_direct {
-        for i in 0..n {
-            for &(v, w, a) in e.iter() {
-                if dist[v] < INF / 2 && dist[v] + a < dist[w] {
-                    dist[w] = dist[v] + a;
-                    if i == n - 1 {
-                        return true;
-                    }
-                }
+/// Return the shortest path tree from `source`. O(nm)
+/// The paths to vertices whose distance is `-inf` are meaningless.
+pub fn bellman_ford_re<W: Weight>(graph: &Graph<W>, source: usize) -> ShortestPathTree<W> {
+    let mut tree = ShortestPathTree::new(graph.n());
+    tree.dist[source] = W::zero();
+    for _ in 0..graph.n() {
+        let mut update = false;
+        for e in graph.arcs().iter() {
+            let d = tree.dist[e.from].saturating_add(e.weight);
+            if tree.dist[e.from] < W::inf() && d < tree.dist[e.to] {
+                tree.dist[e.to] = d;
+                tree.parent[e.to] = Some(*e);
+                update = true;
             }
         }
-    } else {
-        for i in 0..n {
-            for &(v, w, a) in e.iter() {
-                if dist[v] < INF / 2 && dist[v] + a < dist[w] {
-                    dist[w] = dist[v] + a;
-                    if i == n - 1 {
-                        return true;
-                    }
-                }
-                if dist[w] < INF / 2 && dist[w] + a < dist[v] {
-                    dist[v] = dist[w] + a;
-                    if i == n - 1 {
-                        return true;
-                    }
-                }
+        if !update {
+            break;
+        }
+    }
+    tree
+}
+
+/// Return if a negative cycle is reachable from `source`. O(nm)
+pub fn has_negative_cycle<W: Weight>(graph: &Graph<W>, source: usize) -> bool {
+    bellman_ford(graph, source).has_negative_cycle()
+}
+
+/// Return a negative cycle anywhere in `graph` as `(vertices, edge ids)`. O(nm)
+/// The `i`-th edge goes from `vertices[i]` to `vertices[(i + 1) % len]`.
+/// In an undirected graph, a negative edge is a cycle of length 2 using the edge twice.
+pub fn find_negative_cycle<W: Weight>(graph: &Graph<W>) -> Option<(Vec<usize>, Vec<usize>)> {
+    let n = graph.n();
+    let mut tree = ShortestPathTree::new(n);
+    tree.dist = vec![W::zero(); n];
+    let mut last = None;
+    for _ in 0..n {
+        last = None;
+        for e in graph.arcs().iter() {
+            let d = tree.dist[e.from].saturating_add(e.weight);
+            if d < tree.dist[e.to] {
+                tree.dist[e.to] = d;
+                tree.parent[e.to] = Some(*e);
+                last = Some(e.to);
             }
         }
+        last?;
     }
-    false
-}
\ No newline at end of file
+    // After n rounds, going back n times from the last relaxed vertex reaches a cycle.
+    let mut v = last?;
+    for _ in 0..n {
+        v = tree.parent[v]?.from;
+    }
+    let mut arcs = vec![];
+    let mut u = v;
+    loop {
+        let e = tree.parent[u]?;
+        arcs.push(e);
+        u = e.from;
+        if u == v {
+            break;
+        }
+    }
+    arcs.reverse();
+    Some((arcs.iter().map(|e| e.from).collect(), arcs.iter().map(|e| e.id).collect()))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bellman_ford_re_undirected_parent_is_predecessor() {
+        // Both edges of the shortest path to 2 are used against their input direction.
+        let graph = Graph::undirected(3, &[(1, 0, 2), (2, 1, 3), (0, 2, 9)]);
+        let tree = bellman_ford_re(&graph, 0);
+        assert_eq!(tree.dist, vec![0, 2, 5]);
+        assert_eq!(tree.parent[2].map(|e| (e.from, e.to)), Some((1, 2)));
+        assert_eq!(tree.path_to(2), Some(vec![0, 1, 2]));
+        assert_eq!(tree.edge_path_to(2), Some(vec![0, 1]));
+    }
+
+    #[test]
+    fn find_negative_cycle_is_negative() {
+        // 1 -> 2 -> 1 is a negative cycle reachable from 0, and 4 is unreachable.
+        let graph = Graph::directed(5, &[(0, 1, 1), (1, 2, -3), (2, 1, 1), (2, 3, 1), (4, 0, 5)]);
+        let (vertices, ids) = find_negative_cycle(&graph).unwrap();
+        assert_eq!(vertices.len(), ids.len());
+        let mut d = 0;
+        for (i, &id) in ids.iter().enumerate() {
+            let e = graph.edges()[id];
+            assert_eq!((e.from, e.to), (vertices[i], vertices[(i + 1) % vertices.len()]));
+            d += e.weight;
+        }
+        assert!(d < 0);
+        let res = bellman_ford(&graph, 0);
+        assert_eq!(res.negative_infinity, vec![false, true, true, true, false]);
+        assert_eq!(res.get(0), Some(0));
+        assert_eq!(res.get(3), None);
+        assert_eq!(res.get(4), Some(i64::inf()));
+        assert!(!bellman_ford(&graph, 4).negative_infinity[4]);
+        assert!(has_negative_cycle(&graph, 4));
+        assert_eq!(spfa(&graph, 0).negative_infinity, res.negative_infinity);
+
+        let graph = Graph::directed(3, &[(0, 1, -1), (1, 2, -1), (2, 0, 3)]);
+        assert!(find_negative_cycle(&graph).is_none());
+        assert!(!bellman_ford(&graph, 0).has_negative_cycle());
+        // A negative undirected edge is a cycle of length 2.
+        let graph = Graph::undirected(2, &[(0, 1, -1)]);
+        let (vertices, ids) = find_negative_cycle(&graph).unwrap();
+        assert_eq!((vertices.len(), ids), (2, vec![0, 0]));
+    }
+}
@@ -0,0 +1,73 @@
+//! # Boruvka
+//!
+//! [`boruvka_by`] takes an oracle of the cheapest outgoing edge of each component,
+//! which is useful for complete graphs such as XOR or Manhattan distances.
+//!
+use graph::{Edge, Graph, Mst, Weight};
+use union_find::UnionFind;
+/// function. `graph` should be undirected. Return the minimum spanning forest if disconnected.
+pub fn boruvka<W: Weight>(graph: &Graph<W>) -> Mst<W> {
+    boruvka_by(graph.n(), |component, k| {
+        let mut res: Vec<Option<Edge<W>>> = vec![None; k];
+        for &e in graph.edges().iter() {
+            let (v, w) = (component[e.from], component[e.to]);
+            if v == w {
+                continue;
+            }
+            for c in [v, w] {
+                if res[c].is_none_or(|f| (e.weight, e.id) < (f.weight, f.id)) {
+                    res[c] = Some(e);
+                }
+            }
+        }
+        res
+    })
+}
+
+/// Boruvka with an oracle. O(log n) calls of `cheapest`.
+/// `cheapest(component, k)` takes the component `component[v] < k` of each vertex
+/// and returns the cheapest edge between each component and the others (`None` if there is no such edge).
+/// Ties must be broken consistently.
+pub fn boruvka_by<W, F>(n: usize, mut cheapest: F) -> Mst<W>
+where
+    W: Weight,
+    F: FnMut(&[usize], usize) -> Vec<Option<Edge<W>>>,
+{
+    let mut mst = Mst::new(n);
+    let mut union_find = UnionFind::new(n);
+    loop {
+        let (component, k) = components(&mst);
+        let mut update = false;
+        for e in cheapest(&component, k).into_iter().flatten() {
+            if union_find.unite(e.from, e.to) {
+                mst.push(e);
+                update = true;
+            }
+        }
+        if !update {
+            break mst;
+        }
+    }
+}
+fn components<W: Weight>(mst: &Mst<W>) -> (Vec<usize>, usize) {
+    let tree = mst.tree();
+    let mut component = vec![usize::MAX; mst.n()];
+    let mut k = 0;
+    for root in 0..mst.n() {
+        if component[root] != usize::MAX {
+            continue;
+        }
+        component[root] = k;
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            for e in tree[v].iter() {
+                if component[e.to] == usize::MAX {
+                    component[e.to] = k;
+                    stack.push(e.to);
+                }
+            }
+        }
+        k += 1;
+    }
+    (component, k)
+}
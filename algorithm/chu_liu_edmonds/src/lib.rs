@@ -0,0 +1,198 @@
+//! # Chu-Liu/Edmonds
+//!
+//! Minimum spanning arborescence of a directed graph, by contracting cycles
+//! with mergeable heaps and [`UnionFind`].
+//!
+use std::ops::Sub;
+use graph::{Edge, Graph, Weight};
+use union_find::UnionFind;
+/// function. O(m log m)
+/// Return the total cost and the arc into each vertex (`None` for `root`) of a minimum arborescence rooted at `root`,
+/// or `None` if some vertex is unreachable from `root`.
+pub fn chu_liu_edmonds<W>(graph: &Graph<W>, root: usize) -> Option<(W, Vec<Option<Edge<W>>>)>
+where
+    W: Weight + Sub<Output = W>,
+{
+    let n = graph.n();
+    let mut heap = Heap::new();
+    // Contracted cycles get new ids `n, n + 1, ...`.
+    // `node[uf.leader(v)]` is the id of the contracted vertex containing `v`.
+    let mut node = (0..n).collect::<Vec<_>>();
+    let mut heads = vec![None; n];
+    for e in graph.arcs().iter() {
+        heads[e.to] = heap.insert(heads[e.to], *e);
+    }
+    let mut representative = (0..n).collect::<Vec<_>>();
+    let mut children: Vec<Vec<usize>> = vec![vec![]; n];
+    let mut chosen = vec![None; n];
+    let mut seen = vec![None; n];
+    let mut union_find = UnionFind::new(n);
+    let mut cost = W::zero();
+    seen[root] = Some(root);
+    for s in 0..n {
+        let mut u = node[union_find.leader(s)];
+        let mut path = vec![];
+        while seen[u].is_none() {
+            // the cheapest arc into `u` from outside
+            let (e, key) = loop {
+                let (e, key) = heap.top(heads[u]?);
+                heads[u] = heap.pop(heads[u].unwrap());
+                if node[union_find.leader(e.from)] != u {
+                    break (e, key);
+                }
+            };
+            heap.subtract(heads[u], key);
+            cost = cost.saturating_add(key);
+            chosen[u] = Some(e);
+            seen[u] = Some(s);
+            path.push(u);
+            let v = node[union_find.leader(e.from)];
+            if seen[v] != Some(s) {
+                u = v;
+                continue;
+            }
+            // contract the cycle
+            let c = children.len();
+            let mut members = vec![];
+            let mut merged = None;
+            loop {
+                let w = path.pop().unwrap();
+                union_find.unite(representative[w], representative[v]);
+                merged = heap.merge(merged, heads[w]);
+                members.push(w);
+                if w == v {
+                    break;
+                }
+            }
+            node[union_find.leader(representative[v])] = c;
+            representative.push(representative[v]);
+            children.push(members);
+            chosen.push(None);
+            seen.push(None);
+            heads.push(merged);
+            u = c;
+        }
+    }
+    // Expand the contracted vertices from the top. The arc into a contracted vertex replaces
+    // the cycle arc of the member containing its head, and the other members keep their cycle arcs.
+    let mut up = vec![None; children.len()];
+    for (c, members) in children.iter().enumerate() {
+        for &w in members.iter() {
+            up[w] = Some(c);
+        }
+    }
+    let mut parent = vec![None; n];
+    let mut is_removed = vec![false; children.len()];
+    let mut stack = (0..children.len())
+        .filter(|&x| up[x].is_none())
+        .map(|x| (x, chosen[x]))
+        .collect::<Vec<_>>();
+    while let Some((x, e)) = stack.pop() {
+        let mut y = e.map_or(root, |e| e.to);
+        parent[y] = e;
+        let mut path = vec![y];
+        while y != x {
+            y = up[y].unwrap();
+            path.push(y);
+        }
+        for &y in path.iter() {
+            is_removed[y] = true;
+        }
+        for &y in path.iter() {
+            for &w in children[y].iter() {
+                if !is_removed[w] {
+                    stack.push((w, chosen[w]));
+                }
+            }
+        }
+    }
+    Some((cost, parent))
+}
+
+struct Node<W> {
+    arc: Edge<W>,
+    key: W,
+    lazy: W,
+    rank: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+/// leftist heap of arcs with lazy subtraction
+struct Heap<W> {
+    nodes: Vec<Node<W>>,
+}
+impl<W: Weight + Sub<Output = W>> Heap<W> {
+    fn new() -> Self {
+        Self { nodes: vec![] }
+    }
+    fn insert(&mut self, root: Option<usize>, arc: Edge<W>) -> Option<usize> {
+        self.nodes.push(Node { arc, key: arc.weight, lazy: W::zero(), rank: 1, left: None, right: None });
+        let node = Some(self.nodes.len() - 1);
+        self.merge(root, node)
+    }
+    fn top(&self, root: usize) -> (Edge<W>, W) {
+        (self.nodes[root].arc, self.nodes[root].key)
+    }
+    fn pop(&mut self, root: usize) -> Option<usize> {
+        self.push(root);
+        self.merge(self.nodes[root].left, self.nodes[root].right)
+    }
+    /// subtract `x` from all keys, which must not be less than `x`.
+    fn subtract(&mut self, root: Option<usize>, x: W) {
+        if let Some(root) = root {
+            let node = &mut self.nodes[root];
+            node.key = node.key - x;
+            node.lazy = node.lazy.saturating_add(x);
+        }
+    }
+    fn push(&mut self, i: usize) {
+        let lazy = std::mem::replace(&mut self.nodes[i].lazy, W::zero());
+        if lazy != W::zero() {
+            self.subtract(self.nodes[i].left, lazy);
+            self.subtract(self.nodes[i].right, lazy);
+        }
+    }
+    fn rank(&self, i: Option<usize>) -> usize {
+        i.map_or(0, |i| self.nodes[i].rank)
+    }
+    fn merge(&mut self, a: Option<usize>, b: Option<usize>) -> Option<usize> {
+        let (a, b) = match (a, b) {
+            (None, x) | (x, None) => return x,
+            (Some(a), Some(b)) => (a, b),
+        };
+        self.push(a);
+        self.push(b);
+        let (a, b) = if self.nodes[a].key <= self.nodes[b].key { (a, b) } else { (b, a) };
+        let right = self.merge(self.nodes[a].right, Some(b));
+        self.nodes[a].right = right;
+        if self.rank(self.nodes[a].left) < self.rank(right) {
+            let node = &mut self.nodes[a];
+            std::mem::swap(&mut node.left, &mut node.right);
+        }
+        self.nodes[a].rank = self.rank(self.nodes[a].right) + 1;
+        Some(a)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_cycles() {
+        // The cycle 1 <-> 2 is contracted first, and then it forms a cycle with 3.
+        let graph = Graph::directed(4, &[(1, 2, 1), (2, 1, 1), (3, 2, 2), (1, 3, 1), (0, 1, 10), (0, 3, 20)]);
+        let (cost, parent) = chu_liu_edmonds(&graph, 0).unwrap();
+        assert_eq!(cost, 12);
+        let parent = parent.iter().map(|e| e.map(|e| (e.from, e.to, e.weight))).collect::<Vec<_>>();
+        assert_eq!(parent, vec![None, Some((0, 1, 10)), Some((1, 2, 1)), Some((1, 3, 1))]);
+    }
+
+    #[test]
+    fn unreachable_is_none() {
+        // The cycle 2 <-> 3 has no arc from the root.
+        let graph = Graph::directed(4, &[(0, 1, 1), (2, 3, 1), (3, 2, 1), (2, 1, 1)]);
+        assert!(chu_liu_edmonds(&graph, 0).is_none());
+        assert!(chu_liu_edmonds(&graph, 2).is_none());
+        assert_eq!(chu_liu_edmonds(&Graph::directed(1, &[(0, 0, 1)]), 0).map(|res| res.0), Some(0));
+    }
+}
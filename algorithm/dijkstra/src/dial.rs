@@ -0,0 +1,46 @@
+//! # Dial
+//!
+//! Dijkstra with a bucket queue for small integer weights. O(n + m + max_dist)
+//!
+
+use graph::{Graph, ShortestPathTree};
+/// the largest weight allowed, which bounds the number of buckets
+pub const MAX_WEIGHT: u64 = 1 << 20;
+
+/// function. Every weight must be at most [`MAX_WEIGHT`], since it allocates `max_weight + 1` buckets.
+pub fn dial(graph: &Graph<u64>, source: usize) -> Vec<u64> {
+    dial_re(graph, source).dist
+}
+
+/// Return the shortest path tree from `source`. Every weight must be at most [`MAX_WEIGHT`].
+pub fn dial_re(graph: &Graph<u64>, source: usize) -> ShortestPathTree<u64> {
+    let max_weight = graph.arcs().iter().map(|e| e.weight).max().unwrap_or(0);
+    assert!(max_weight <= MAX_WEIGHT, "dial: weight {max_weight} exceeds MAX_WEIGHT, use dijkstra or radix_heap_dijkstra");
+    // Every queued distance is in [d, d + max_weight], so `max_weight + 1` cyclic buckets are enough.
+    let size = max_weight as usize + 1;
+    let mut buckets = vec![vec![]; size];
+    let mut tree = ShortestPathTree::new(graph.n());
+    tree.dist[source] = 0;
+    buckets[0].push(source);
+    let mut rest = 1;
+    let mut d = 0;
+    while rest > 0 {
+        let i = (d % size as u64) as usize;
+        while let Some(v) = buckets[i].pop() {
+            rest -= 1;
+            if tree.dist[v] != d {
+                continue;
+            }
+            for e in graph[v].iter() {
+                if d + e.weight < tree.dist[e.to] {
+                    tree.dist[e.to] = d + e.weight;
+                    tree.parent[e.to] = Some(*e);
+                    buckets[((d + e.weight) % size as u64) as usize].push(e.to);
+                    rest += 1;
+                }
+            }
+        }
+        d += 1;
+    }
+    tree
+}
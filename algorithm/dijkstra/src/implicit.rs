@@ -0,0 +1,121 @@
+//! # Implicit Dijkstra
+//!
+//! Dijkstra on a graph given by a neighbor function over hashable states,
+//! and a struct [`Grid`] which gives the neighbors in a grid with walls.
+//!
+
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap}, hash::Hash};
+use graph::Weight;
+/// 4-neighborhood
+pub const DIR4: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+/// 8-neighborhood
+pub const DIR8: [(isize, isize); 8] = [(1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1)];
+
+/// function. `neighbors(s)` returns the pairs `(t, weight)` of arcs going out of `s`.
+/// Return the distances of reachable states.
+pub fn implicit_dijkstra<S, W, F, I>(sources: impl IntoIterator<Item = (S, W)>, neighbors: F) -> HashMap<S, W>
+where
+    S: Clone + Eq + Hash,
+    W: Weight,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, W)>,
+{
+    implicit_dijkstra_re(sources, neighbors).into_iter()
+        .map(|(s, (d, _))| (s, d))
+        .collect()
+}
+
+/// Return the distance and the previous state on the shortest path of reachable states.
+/// The previous state of sources is `None`.
+pub fn implicit_dijkstra_re<S, W, F, I>(sources: impl IntoIterator<Item = (S, W)>, mut neighbors: F) -> HashMap<S, (W, Option<S>)>
+where
+    S: Clone + Eq + Hash,
+    W: Weight,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, W)>,
+{
+    // States are numbered in order of appearance so that the heap does not need `S: Ord`.
+    let mut index = HashMap::new();
+    let mut states = vec![];
+    let mut dist: Vec<(W, Option<usize>)> = vec![];
+    let mut heap = BinaryHeap::new();
+    let mut id = |s: S, states: &mut Vec<S>, dist: &mut Vec<(W, Option<usize>)>| {
+        *index.entry(s.clone()).or_insert_with(|| {
+            states.push(s);
+            dist.push((W::inf(), None));
+            states.len() - 1
+        })
+    };
+    for (s, d) in sources {
+        let i = id(s, &mut states, &mut dist);
+        if d < dist[i].0 {
+            dist[i] = (d, None);
+            heap.push((Reverse(d), i));
+        }
+    }
+    while let Some((Reverse(d), i)) = heap.pop() {
+        if dist[i].0 < d {
+            continue;
+        }
+        for (t, weight) in neighbors(&states[i]) {
+            let j = id(t, &mut states, &mut dist);
+            let d = d.saturating_add(weight);
+            if d < dist[j].0 {
+                dist[j] = (d, Some(i));
+                heap.push((Reverse(d), j));
+            }
+        }
+    }
+    dist.iter()
+        .enumerate()
+        .filter(|(_, &(d, _))| d < W::inf())
+        .map(|(i, &(d, p))| (states[i].clone(), (d, p.map(|p| states[p].clone()))))
+        .collect()
+}
+
+/// struct of Grid with walls
+pub struct Grid<'a> {
+    is_wall: Vec<Vec<bool>>,
+    directions: &'a [(isize, isize)],
+}
+impl<'a> Grid<'a> {
+    /// Constructor of Grid. `directions` is [`DIR4`], [`DIR8`] or any moves.
+    pub fn new(is_wall: Vec<Vec<bool>>, directions: &'a [(isize, isize)]) -> Self {
+        Self {
+            is_wall,
+            directions,
+        }
+    }
+
+    /// Constructor of Grid from rows of bytes whose walls are `wall`.
+    pub fn from_bytes<T: AsRef<[u8]>>(grid: &[T], wall: u8, directions: &'a [(isize, isize)]) -> Self {
+        let is_wall = grid.iter()
+            .map(|row| row.as_ref().iter().map(|&c| c == wall).collect())
+            .collect();
+        Self::new(is_wall, directions)
+    }
+
+    /// Return the number of rows.
+    pub fn height(&self) -> usize {
+        self.is_wall.len()
+    }
+
+    /// Return the number of columns.
+    pub fn width(&self) -> usize {
+        self.is_wall.first().map_or(0, |row| row.len())
+    }
+
+    /// Return if `(i, j)` is a wall.
+    pub fn is_wall(&self, (i, j): (usize, usize)) -> bool {
+        self.is_wall[i][j]
+    }
+
+    /// Return the cells which are not walls next to `(i, j)`.
+    pub fn neighbors(&self, &(i, j): &(usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.directions.iter().filter_map(move |&(di, dj)| {
+            let i = i.checked_add_signed(di)?;
+            let j = j.checked_add_signed(dj)?;
+            (i < self.height() && j < self.width() && !self.is_wall[i][j]).then_some((i, j))
+        })
+    }
+}
@@ -0,0 +1,241 @@
+//! # K shortest paths
+//!
+//! [`kth_shortest_walks`] returns the k shortest walk lengths to every vertex,
+//! [`yen`] the k shortest simple paths and [`eppstein`] the k shortest walks between two vertices.
+//!
+
+use std::{cmp::Reverse, collections::{BinaryHeap, HashSet}, ops::Sub};
+use graph::{Edge, Graph, Weight};
+use crate::{dijkstra_re, Dijkstra};
+/// Return the k smallest lengths of walks from `source` to each vertex in ascending order. O(k (n + m) log(km))
+pub fn kth_shortest_walks<W: Weight>(graph: &Graph<W>, source: usize, k: usize) -> Vec<Vec<W>> {
+    let mut res = vec![vec![]; graph.n()];
+    let mut heap = BinaryHeap::new();
+    heap.push((Reverse(W::zero()), source));
+    while let Some((Reverse(d), v)) = heap.pop() {
+        if res[v].len() == k {
+            continue;
+        }
+        res[v].push(d);
+        for e in graph[v].iter() {
+            if res[e.to].len() < k {
+                heap.push((Reverse(d.saturating_add(e.weight)), e.to));
+            }
+        }
+    }
+    res
+}
+
+/// Yen's algorithm. O(kn (n + m) log m)
+/// Return the k shortest simple paths from `s` to `t` as `(length, arcs)` in ascending order of length.
+pub fn yen<W: Weight>(graph: &Graph<W>, s: usize, t: usize, k: usize) -> Vec<(W, Vec<Edge<W>>)> {
+    if k == 0 {
+        return vec![];
+    }
+    let Some(path) = dijkstra_re(graph, s).arc_path_to(t) else {
+        return vec![];
+    };
+    let key = |path: &[Edge<W>]| path.iter().map(|e| (e.id, e.to)).collect::<Vec<_>>();
+    let mut res = vec![(length(&path), path)];
+    let mut candidates: Vec<(W, Vec<Edge<W>>)> = vec![];
+    let mut seen = HashSet::new();
+    seen.insert(key(&res[0].1));
+    while res.len() < k {
+        let last = &res.last().unwrap().1;
+        let mut is_root = vec![false; graph.n()];
+        for i in 0..last.len() {
+            let spur = last[i].from;
+            let root = &last[..i];
+            is_root[spur] = true;
+            let mut is_banned = HashSet::new();
+            for (_, path) in res.iter() {
+                if path.len() > i && key(&path[..i]) == key(root) {
+                    is_banned.insert(path[i].id);
+                }
+            }
+            // The first arc of the spur path is chosen among the sources.
+            let mut first: Vec<Option<Edge<W>>> = vec![None; graph.n()];
+            for e in graph[spur].iter() {
+                if !is_banned.contains(&e.id) && !is_root[e.to] && first[e.to].is_none_or(|f| e.weight < f.weight) {
+                    first[e.to] = Some(*e);
+                }
+            }
+            let tree = Dijkstra::new(graph)
+                .sources(first.iter().flatten().map(|e| (e.to, e.weight)))
+                .target(t)
+                .filter(|v| !is_root[v])
+                .run();
+            if let Some(spur_path) = tree.arc_path_to(t) {
+                let head = spur_path.first().map_or(t, |e| e.from);
+                let mut path = root.to_vec();
+                path.push(first[head].unwrap());
+                path.extend(spur_path);
+                if seen.insert(key(&path)) {
+                    candidates.push((length(&path), path));
+                }
+            }
+        }
+        let Some(i) = (0..candidates.len()).min_by_key(|&i| candidates[i].0) else {
+            break;
+        };
+        res.push(candidates.swap_remove(i));
+    }
+    res
+}
+fn length<W: Weight>(path: &[Edge<W>]) -> W {
+    path.iter().fold(W::zero(), |d, e| d.saturating_add(e.weight))
+}
+
+/// Eppstein's algorithm. O(m log m + k log k)
+/// Return the k smallest lengths of walks from `s` to `t` in ascending order.
+pub fn eppstein<W: Weight + Sub<Output = W>>(graph: &Graph<W>, s: usize, t: usize, k: usize) -> Vec<W> {
+    let n = graph.n();
+    // shortest path tree to `t`. The ids of `reverse` are the indices of `graph.arcs()`.
+    let reverse = Graph::directed(n, &graph.arcs().iter().map(|e| (e.to, e.from, e.weight)).collect::<Vec<_>>());
+    let tree = dijkstra_re(&reverse, t);
+    let dist = &tree.dist;
+    if dist[s] == W::inf() || k == 0 {
+        return vec![];
+    }
+    // heap[v] contains the sidetracks of every vertex on the tree path from `v` to `t`.
+    // Vertices are processed from `t` along the tree.
+    let mut children = vec![vec![]; n];
+    for v in 0..n {
+        if let Some(e) = tree.parent[v] {
+            children[e.from].push(v);
+        }
+    }
+    let mut heap = PersistentHeap::new();
+    let mut root = vec![None; n];
+    let mut stack = vec![t];
+    while let Some(v) = stack.pop() {
+        let next = tree.parent[v].map(|e| graph.arcs()[e.id]);
+        let mut r = next.and_then(|e| root[e.to]);
+        for e in graph[v].iter() {
+            let is_tree = next.is_some_and(|next| (next.id, next.to) == (e.id, e.to));
+            if dist[e.to] < W::inf() && !is_tree {
+                r = heap.insert(r, e.weight.saturating_add(dist[e.to]) - dist[v], e.to);
+            }
+        }
+        root[v] = r;
+        stack.extend(children[v].iter().copied());
+    }
+    let mut res = vec![dist[s]];
+    let mut queue = BinaryHeap::new();
+    if let Some(r) = root[s] {
+        queue.push((Reverse(dist[s].saturating_add(heap.nodes[r].key)), r));
+    }
+    while let Some((Reverse(d), i)) = queue.pop() {
+        if res.len() == k {
+            break;
+        }
+        res.push(d);
+        let node = &heap.nodes[i];
+        for child in [node.left, node.right].into_iter().flatten() {
+            queue.push((Reverse((d - node.key).saturating_add(heap.nodes[child].key)), child));
+        }
+        if let Some(r) = root[node.to] {
+            queue.push((Reverse(d.saturating_add(heap.nodes[r].key)), r));
+        }
+    }
+    res
+}
+
+#[derive(Clone)]
+struct Node<W> {
+    key: W,
+    to: usize,
+    rank: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+/// persistent leftist heap
+struct PersistentHeap<W> {
+    nodes: Vec<Node<W>>,
+}
+impl<W: Weight> PersistentHeap<W> {
+    fn new() -> Self {
+        Self { nodes: vec![] }
+    }
+    fn insert(&mut self, root: Option<usize>, key: W, to: usize) -> Option<usize> {
+        self.nodes.push(Node { key, to, rank: 1, left: None, right: None });
+        let node = Some(self.nodes.len() - 1);
+        self.merge(root, node)
+    }
+    fn rank(&self, i: Option<usize>) -> usize {
+        i.map_or(0, |i| self.nodes[i].rank)
+    }
+    fn merge(&mut self, a: Option<usize>, b: Option<usize>) -> Option<usize> {
+        let (a, b) = match (a, b) {
+            (None, x) | (x, None) => return x,
+            (Some(a), Some(b)) if self.nodes[a].key <= self.nodes[b].key => (a, b),
+            (Some(a), Some(b)) => (b, a),
+        };
+        let mut node = self.nodes[a].clone();
+        node.right = self.merge(node.right, Some(b));
+        if self.rank(node.left) < self.rank(node.right) {
+            std::mem::swap(&mut node.left, &mut node.right);
+        }
+        node.rank = self.rank(node.right) + 1;
+        self.nodes.push(node);
+        Some(self.nodes.len() - 1)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Check that `path` is a simple path from `s` to `t` of `length`, and return its edge ids.
+    fn check(path: &(u64, Vec<Edge<u64>>), s: usize, t: usize) -> Vec<usize> {
+        let (length, arcs) = path;
+        let mut vertices = vec![s];
+        for e in arcs.iter() {
+            assert_eq!(e.from, *vertices.last().unwrap());
+            vertices.push(e.to);
+        }
+        assert_eq!(*vertices.last().unwrap(), t);
+        let mut sorted = vertices.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), vertices.len());
+        assert_eq!(arcs.iter().map(|e| e.weight).sum::<u64>(), *length);
+        arcs.iter().map(|e| e.id).collect()
+    }
+
+    #[test]
+    fn yen_directed_with_ties_and_parallel_edges() {
+        // Edges 0 and 1 are parallel, and there are ties of length 2 and 3.
+        let graph = Graph::directed(4, &[(0, 1, 1), (0, 1, 1), (1, 3, 2), (0, 2, 2), (2, 3, 1), (1, 2, 0), (0, 3, 5)]);
+        let paths = yen(&graph, 0, 3, 10);
+        assert_eq!(paths.iter().map(|p| p.0).collect::<Vec<_>>(), vec![2, 2, 3, 3, 3, 5]);
+        let mut ids = paths.iter().map(|p| check(p, 0, 3)).collect::<Vec<_>>();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 6);
+        assert_eq!(yen(&graph, 0, 3, 3).iter().map(|p| p.0).collect::<Vec<_>>(), vec![2, 2, 3]);
+        assert!(yen(&graph, 3, 0, 3).is_empty());
+        assert!(yen(&graph, 0, 3, 0).is_empty());
+    }
+
+    #[test]
+    fn yen_undirected() {
+        let graph = Graph::undirected(4, &[(0, 1, 1), (1, 2, 1), (0, 2, 2), (2, 3, 1), (1, 3, 4)]);
+        let paths = yen(&graph, 0, 3, 10);
+        assert_eq!(paths.iter().map(|p| p.0).collect::<Vec<_>>(), vec![3, 3, 5, 7]);
+        for p in paths.iter() {
+            check(p, 0, 3);
+        }
+    }
+
+    #[test]
+    fn eppstein_directed_with_ties_and_parallel_edges() {
+        let graph = Graph::directed(4, &[(0, 1, 1), (0, 1, 1), (1, 3, 2), (0, 2, 2), (2, 3, 1), (1, 2, 0), (0, 3, 5)]);
+        assert_eq!(eppstein(&graph, 0, 3, 10), vec![2, 2, 3, 3, 3, 5]);
+        assert_eq!(eppstein(&graph, 0, 3, 4), vec![2, 2, 3, 3]);
+        assert!(eppstein(&graph, 3, 0, 4).is_empty());
+        // Walks may repeat the cycle 0 -> 1 -> 0.
+        let graph = Graph::directed(2, &[(0, 1, 1), (1, 0, 1)]);
+        assert_eq!(eppstein(&graph, 0, 1, 3), vec![1, 3, 5]);
+        assert_eq!(kth_shortest_walks(&graph, 0, 3)[1], vec![1, 3, 5]);
+    }
+}
@@ -1,64 +1,153 @@
 //! # Dijkstra
-//! 
-//! 
-const INF: u64 = std::u64::MAX >> 1;
+//!
+//! Define a struct [`Dijkstra`] which is a builder of Dijkstra with several sources,
+//! targets to stop at, a distance cap and a vertex filter.
+//! Weights must be non-negative.
+//! [`zero_one_bfs()`], [`dial()`] and [`radix_heap_dijkstra`] are faster alternatives for special weights.
+//! [`implicit_dijkstra`] works on graphs given by a neighbor function, such as grids.
+//! [`k_shortest`] gives the k shortest walks or simple paths.
+//!
+pub mod dial;
+pub mod implicit;
+pub mod k_shortest;
+pub mod radix_heap;
+pub mod zero_one_bfs;
+pub use dial::{dial, dial_re};
+pub use implicit::{implicit_dijkstra, implicit_dijkstra_re, Grid};
+pub use k_shortest::{eppstein, kth_shortest_walks, yen};
+pub use radix_heap::{radix_heap_dijkstra, radix_heap_dijkstra_re, RadixHeap};
+pub use zero_one_bfs::{zero_one_bfs, zero_one_bfs_re};
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+use graph::{Graph, ShortestPathTree, Weight};
 /// function
-pub fn dijkstra(n: usize, source: usize, e: &[(usize, usize, u64)], is_direct: bool) -> Vec<u64> {
-    let _e = e;
-    let mut e = vec![vec![]; n];
-    for &(v, w, a) in _e.iter() {
-        e[v].push((w, a));
-    }
-    if !is_direct {
-        for &(v, w, a) in _e.iter() {
-            e[w].push((v, a));
+pub fn dijkstra<W: Weight>(graph: &Graph<W>, source: usize) -> Vec<W> {
+    dijkstra_re(graph, source).dist
+}
+
+/// Return the shortest path tree from `source`.
+pub fn dijkstra_re<W: Weight>(graph: &Graph<W>, source: usize) -> ShortestPathTree<W> {
+    Dijkstra::new(graph).source(source, W::zero()).run()
+}
+
+/// struct of Dijkstra builder
+pub struct Dijkstra<'a, W, F = fn(usize) -> bool> {
+    graph: &'a Graph<W>,
+    sources: Vec<(usize, W)>,
+    targets: Vec<usize>,
+    limit: Option<W>,
+    filter: F,
+}
+impl<'a, W: Weight> Dijkstra<'a, W> {
+    /// Constructor of Dijkstra on `graph` without sources.
+    pub fn new(graph: &'a Graph<W>) -> Self {
+        Self {
+            graph,
+            sources: vec![],
+            targets: vec![],
+            limit: None,
+            filter: |_| true,
         }
     }
-    let mut dist = vec![INF; n];
-    dist[source] = 0;
-    let mut heap = std::collections::BinaryHeap::new();
-    heap.push((!0, source));
-    while let Some((d, v)) = heap.pop() {
-        let d = !d;
-        if dist[v] < d {
-            continue;
-        }
-        for &(w, dd) in e[v].iter() {
-            if dist[v] + dd < dist[w] {
-                dist[w] = dist[v] + dd;
-                heap.push((!(dist[v] + dd), w));
-            }
-        }
-     }
-    dist
 }
+impl<'a, W: Weight, F: FnMut(usize) -> bool> Dijkstra<'a, W, F> {
+    /// Add a source `v` with initial distance `d`.
+    pub fn source(mut self, v: usize, d: W) -> Self {
+        self.sources.push((v, d));
+        self
+    }
+
+    /// Add sources `(v, d)`.
+    pub fn sources<I: IntoIterator<Item = (usize, W)>>(mut self, sources: I) -> Self {
+        self.sources.extend(sources);
+        self
+    }
+
+    /// Add a target. The search stops when the distances of all targets are determined.
+    pub fn target(mut self, v: usize) -> Self {
+        self.targets.push(v);
+        self
+    }
 
-pub fn dijkstra_re(n: usize, source: usize, e: &[(usize, usize, u64)], is_direct: bool) -> Vec<(u64, Option<usize>)> {
-    let _e = e;
-    let mut e = vec![vec![]; n];
-    for &(v, w, a) in _e.iter() {
-        e[v].push((w, a));
+    /// Add targets.
+    pub fn targets<I: IntoIterator<Item = usize>>(mut self, targets: I) -> Self {
+        self.targets.extend(targets);
+        self
     }
-    if !is_direct {
-        for &(v, w, a) in _e.iter() {
-            e[w].push((v, a));
+
+    /// Vertices farther than `limit` are left unreachable.
+    pub fn limit(mut self, limit: W) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Vertices `v` with `filter(v) == false` are never visited, even if they are sources.
+    pub fn filter<G: FnMut(usize) -> bool>(self, filter: G) -> Dijkstra<'a, W, G> {
+        Dijkstra {
+            graph: self.graph,
+            sources: self.sources,
+            targets: self.targets,
+            limit: self.limit,
+            filter,
         }
     }
-    let mut dist = vec![(INF, None); n];
-    dist[source] = (0, Some(source));
-    let mut heap = std::collections::BinaryHeap::new();
-    heap.push((!0, source));
-    while let Some((d, v)) = heap.pop() {
-        let d = !d;
-        if dist[v].0 < d {
-            continue;
+
+    /// Run Dijkstra and return the shortest path forest. O((n + m) log m)
+    /// If there are targets, the distances of vertices farther than all of them may not be the shortest.
+    pub fn run(mut self) -> ShortestPathTree<W> {
+        let graph = self.graph;
+        let limit = self.limit.unwrap_or(W::inf());
+        let mut tree = ShortestPathTree::new(graph.n());
+        let mut is_target = vec![false; graph.n()];
+        let mut rest = 0;
+        for &v in self.targets.iter() {
+            if !is_target[v] {
+                is_target[v] = true;
+                rest += 1;
+            }
+        }
+        let mut heap = BinaryHeap::new();
+        for &(v, d) in self.sources.iter() {
+            if d < tree.dist[v] && d <= limit && (self.filter)(v) {
+                tree.dist[v] = d;
+                heap.push((Reverse(d), v));
+            }
         }
-        for &(w, dd) in e[v].iter() {
-            if dist[v].0 + dd < dist[w].0 {
-                dist[w] = (dist[v].0 + dd, Some(v));
-                heap.push((!(dist[v].0 + dd), w));
+        while let Some((Reverse(d), v)) = heap.pop() {
+            if tree.dist[v] < d {
+                continue;
+            }
+            if is_target[v] {
+                is_target[v] = false;
+                rest -= 1;
+                if rest == 0 {
+                    break;
+                }
+            }
+            for e in graph[v].iter() {
+                let d = d.saturating_add(e.weight);
+                if d < tree.dist[e.to] && d <= limit && (self.filter)(e.to) {
+                    tree.dist[e.to] = d;
+                    tree.parent[e.to] = Some(*e);
+                    heap.push((Reverse(d), e.to));
+                }
             }
         }
-     }
-    dist
-}
\ No newline at end of file
+        tree
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dijkstra_re_undirected_parent_is_predecessor() {
+        // Both edges of the shortest path to 2 are used against their input direction,
+        // and edge 2 is the cheaper of the parallel edges between 1 and 2.
+        let graph = Graph::undirected(3, &[(1, 0, 2), (2, 1, 5), (2, 1, 3), (0, 2, 9)]);
+        let tree = dijkstra_re(&graph, 0);
+        assert_eq!(tree.dist, vec![0, 2, 5]);
+        assert_eq!(tree.path_to(2), Some(vec![0, 1, 2]));
+        assert_eq!(tree.edge_path_to(2), Some(vec![0, 2]));
+    }
+}
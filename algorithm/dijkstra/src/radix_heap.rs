@@ -0,0 +1,92 @@
+//! # Radix heap
+//!
+//! Define a struct [`RadixHeap`], which is a monotone priority queue of `u64` keys,
+//! and Dijkstra using it.
+//!
+
+use graph::{Graph, ShortestPathTree};
+/// struct of RadixHeap. The pushed keys must not be less than the last popped key.
+pub struct RadixHeap<T> {
+    last: u64,
+    len: usize,
+    buckets: Vec<Vec<(u64, T)>>,
+}
+impl<T> RadixHeap<T> {
+    /// Constructor of empty RadixHeap
+    pub fn new() -> Self {
+        Self {
+            last: 0,
+            len: 0,
+            buckets: (0..=64).map(|_| vec![]).collect(),
+        }
+    }
+
+    /// Return the number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return if self is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Push `value` with `key`. amortized O(1)
+    pub fn push(&mut self, key: u64, value: T) {
+        assert!(self.last <= key);
+        self.buckets[bucket(key, self.last)].push((key, value));
+        self.len += 1;
+    }
+
+    /// Pop an element with the minimum key. amortized O(log max_key)
+    pub fn pop(&mut self) -> Option<(u64, T)> {
+        if self.len == 0 {
+            return None;
+        }
+        if self.buckets[0].is_empty() {
+            let i = self.buckets.iter().position(|b| !b.is_empty()).unwrap();
+            let bucket_i = std::mem::take(&mut self.buckets[i]);
+            self.last = bucket_i.iter().map(|&(key, _)| key).min().unwrap();
+            for (key, value) in bucket_i {
+                self.buckets[bucket(key, self.last)].push((key, value));
+            }
+        }
+        self.len -= 1;
+        self.buckets[0].pop()
+    }
+}
+impl<T> Default for RadixHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+fn bucket(key: u64, last: u64) -> usize {
+    64 - (key ^ last).leading_zeros() as usize
+}
+
+/// function
+pub fn radix_heap_dijkstra(graph: &Graph<u64>, source: usize) -> Vec<u64> {
+    radix_heap_dijkstra_re(graph, source).dist
+}
+
+/// Return the shortest path tree from `source`.
+pub fn radix_heap_dijkstra_re(graph: &Graph<u64>, source: usize) -> ShortestPathTree<u64> {
+    let mut tree = ShortestPathTree::new(graph.n());
+    tree.dist[source] = 0;
+    let mut heap = RadixHeap::new();
+    heap.push(0, source);
+    while let Some((d, v)) = heap.pop() {
+        if tree.dist[v] < d {
+            continue;
+        }
+        for e in graph[v].iter() {
+            let d = d.saturating_add(e.weight);
+            if d < tree.dist[e.to] {
+                tree.dist[e.to] = d;
+                tree.parent[e.to] = Some(*e);
+                heap.push(d, e.to);
+            }
+        }
+    }
+    tree
+}
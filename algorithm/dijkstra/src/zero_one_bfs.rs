@@ -0,0 +1,37 @@
+//! # 0-1 BFS
+//!
+//! Shortest path for graphs whose weights are `0` or a constant `c`. O(n + m)
+//!
+
+use std::collections::VecDeque;
+use graph::{Graph, ShortestPathTree, Weight};
+/// function. All non-zero weights must be the same.
+pub fn zero_one_bfs<W: Weight>(graph: &Graph<W>, source: usize) -> Vec<W> {
+    zero_one_bfs_re(graph, source).dist
+}
+
+/// Return the shortest path tree from `source`. All non-zero weights must be the same.
+pub fn zero_one_bfs_re<W: Weight>(graph: &Graph<W>, source: usize) -> ShortestPathTree<W> {
+    let mut tree = ShortestPathTree::new(graph.n());
+    tree.dist[source] = W::zero();
+    let mut deque = VecDeque::new();
+    deque.push_back((W::zero(), source));
+    while let Some((d, v)) = deque.pop_front() {
+        if tree.dist[v] < d {
+            continue;
+        }
+        for e in graph[v].iter() {
+            let d = d.saturating_add(e.weight);
+            if d < tree.dist[e.to] {
+                tree.dist[e.to] = d;
+                tree.parent[e.to] = Some(*e);
+                if e.weight == W::zero() {
+                    deque.push_front((d, e.to));
+                } else {
+                    deque.push_back((d, e.to));
+                }
+            }
+        }
+    }
+    tree
+}
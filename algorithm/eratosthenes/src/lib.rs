@@ -3,6 +3,11 @@
 //! 
 //! Define a struct [`Eratosthenes`]
 //! 
+//! [`LinearSieve`] also gives the list of primes and multiplicative functions.
+//! 
+pub mod linear_sieve;
+pub use linear_sieve::LinearSieve;
+
 /// sturct
 pub struct Erathosthenes {
     values: Vec<Option<usize>>,
@@ -11,7 +16,7 @@ impl Erathosthenes {
     /// Constructor of Ertosthenes to `max_value`
     pub fn new(max_value: usize) -> Self {
         let mut values = (0..=max_value)
-            .map(|i| Some(i))
+            .map(Some)
             .collect::<Vec<_>>();
         values[0] = None;
         values[1] = None;
@@ -38,7 +43,7 @@ impl Erathosthenes {
     /// Return prime factorization.
     pub fn prime_factorize(&self, mut value: usize) -> Vec<(usize, usize)> {
         let mut res = vec![];
-        while value > 0 {
+        while value > 1 {
             let factor = self.values[value];
             let mut exp = 0;
             while self.values[value] == factor {
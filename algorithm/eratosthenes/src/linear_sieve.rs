@@ -0,0 +1,107 @@
+//! # Linear sieve
+//!
+//! Define a struct [`LinearSieve`] which also computes multiplicative functions up to the bound in O(n).
+//!
+use std::ops::Mul;
+/// struct of the linear sieve
+pub struct LinearSieve {
+    // the least prime factor, 0 for 0 and 1
+    lpf: Vec<usize>,
+    primes: Vec<usize>,
+}
+impl LinearSieve {
+    /// Constructor of LinearSieve to `max_value`. O(max_value)
+    pub fn new(max_value: usize) -> Self {
+        let mut lpf = vec![0; max_value + 1];
+        let mut primes = vec![];
+        for i in 2..=max_value {
+            if lpf[i] == 0 {
+                lpf[i] = i;
+                primes.push(i);
+            }
+            // Every composite is marked once by its least prime factor.
+            let l = lpf[i];
+            for &p in primes.iter().take_while(|&&p| p <= l && i * p <= max_value) {
+                lpf[i * p] = p;
+            }
+        }
+        Self {
+            lpf,
+            primes,
+        }
+    }
+
+    /// Return the primes up to `max_value` in ascending order.
+    pub fn primes(&self) -> &[usize] {
+        &self.primes
+    }
+
+    /// Return the least prime factor of `value`, or `None` if `value` is 0 or 1.
+    pub fn lpf(&self, value: usize) -> Option<usize> {
+        if value < 2 {
+            None
+        } else {
+            Some(self.lpf[value])
+        }
+    }
+
+    /// Return if value is prime number.
+    pub fn is_prime(&self, value: usize) -> bool {
+        value >= 2 && self.lpf[value] == value
+    }
+
+    /// Return prime factorization. O(log value)
+    pub fn prime_factorize(&self, mut value: usize) -> Vec<(usize, usize)> {
+        let mut res: Vec<(usize, usize)> = vec![];
+        while value > 1 {
+            let p = self.lpf[value];
+            match res.last_mut() {
+                Some((q, exp)) if *q == p => *exp += 1,
+                _ => res.push((p, 1)),
+            }
+            value /= p;
+        }
+        res
+    }
+
+    /// Return `[f(0), f(1), ..., f(max_value)]` of the multiplicative function `f` with `f(1) = one`
+    /// and `f(p^k) = prime_power(p, k, p^k)`. `f(0)` is `one` and meaningless. O(max_value)
+    pub fn multiplicative<T, F>(&self, one: T, mut prime_power: F) -> Vec<T>
+    where
+        T: Copy + Mul<Output = T>,
+        F: FnMut(usize, usize, usize) -> T,
+    {
+        let n = self.lpf.len();
+        let mut res = vec![one; n];
+        // the power of the least prime factor in `i` as `(p^k, k)`
+        let mut power = vec![(1, 0); n];
+        for i in 2..n {
+            let p = self.lpf[i];
+            let q = i / p;
+            power[i] = if self.lpf[q] == p { (power[q].0 * p, power[q].1 + 1) } else { (p, 1) };
+            let (pk, k) = power[i];
+            res[i] = if pk == i { prime_power(p, k, pk) } else { res[pk] * res[i / pk] };
+        }
+        res
+    }
+
+    /// Return Euler's totient function up to `max_value`.
+    pub fn euler_phi(&self) -> Vec<usize> {
+        self.multiplicative(1, |p, _, pk| pk / p * (p - 1))
+    }
+
+    /// Return the Mobius function up to `max_value`.
+    pub fn mobius(&self) -> Vec<i64> {
+        self.multiplicative(1, |_, k, _| if k == 1 { -1 } else { 0 })
+    }
+
+    /// Return the number of divisors up to `max_value`.
+    pub fn divisor_count(&self) -> Vec<usize> {
+        self.multiplicative(1, |_, k, _| k + 1)
+    }
+
+    /// Return the sum of divisors up to `max_value`.
+    pub fn divisor_sum(&self) -> Vec<u64> {
+        self.multiplicative(1, |p, _, pk| ((pk * p - 1) / (p - 1)) as u64)
+    }
+}
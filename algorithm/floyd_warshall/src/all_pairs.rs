@@ -0,0 +1,78 @@
+//! # All pairs shortest paths
+//!
+//! Define a struct [`AllPairs`] which keeps all pairs shortest paths under edge insertion.
+//!
+
+use graph::{Edge, Graph, ShortestPathTree, Weight};
+use crate::floyd_warshall_re;
+/// struct of all pairs shortest paths
+pub struct AllPairs<W> {
+    is_direct: bool,
+    m: usize,
+    trees: Vec<ShortestPathTree<W>>,
+}
+impl<W: Weight> AllPairs<W> {
+    /// Constructor of AllPairs by Floyd Warshall. O(n^3)
+    pub fn new(graph: &Graph<W>) -> Self {
+        Self {
+            is_direct: graph.is_direct(),
+            m: graph.m(),
+            trees: floyd_warshall_re(graph),
+        }
+    }
+
+    /// Return the distance from `u` to `v`.
+    pub fn dist(&self, u: usize, v: usize) -> W {
+        self.trees[u].dist[v]
+    }
+
+    /// Return the shortest path tree from `u`.
+    pub fn tree(&self, u: usize) -> &ShortestPathTree<W> {
+        &self.trees[u]
+    }
+
+    /// Return the vertices of the shortest path from `u` to `v`.
+    pub fn path(&self, u: usize, v: usize) -> Option<Vec<usize>> {
+        self.trees[u].path_to(v)
+    }
+
+    /// Return the edge ids of the shortest path from `u` to `v`.
+    /// The edges added by [`AllPairs::add_edge`] have ids `m, m + 1, ...` in order.
+    pub fn edge_path(&self, u: usize, v: usize) -> Option<Vec<usize>> {
+        self.trees[u].edge_path_to(v)
+    }
+
+    /// Return if there is a negative cycle. Then the distances are meaningless.
+    pub fn has_negative_cycle(&self) -> bool {
+        (0..self.trees.len()).any(|v| self.trees[v].dist[v] < W::zero())
+    }
+
+    /// Add an edge from `u` to `v` (and from `v` to `u` if undirected) and update all distances. O(n^2)
+    pub fn add_edge(&mut self, u: usize, v: usize, weight: W) {
+        let id = self.m;
+        self.m += 1;
+        self.add_arc(Edge { from: u, to: v, weight, id });
+        if !self.is_direct {
+            self.add_arc(Edge { from: v, to: u, weight, id });
+        }
+    }
+
+    fn add_arc(&mut self, e: Edge<W>) {
+        let n = self.trees.len();
+        let to_from = (0..n).map(|s| self.trees[s].dist[e.from]).collect::<Vec<_>>();
+        let from_to = self.trees[e.to].clone();
+        for (s, &d) in to_from.iter().enumerate() {
+            if d == W::inf() {
+                continue;
+            }
+            let d = d.saturating_add(e.weight);
+            for t in 0..n {
+                let d = d.saturating_add(from_to.dist[t]);
+                if d < self.trees[s].dist[t] {
+                    self.trees[s].dist[t] = d;
+                    self.trees[s].parent[t] = if t == e.to { Some(e) } else { from_to.parent[t] };
+                }
+            }
+        }
+    }
+}
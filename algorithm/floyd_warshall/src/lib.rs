@@ -1,55 +1,112 @@
 //! # Floys Warshall
-//! 
-const INF: i64 = std::i64::MAX >> 1;
+//!
+//! [`johnson`] is an alternative for sparse graphs.
+//! [`AllPairs`] supports edge insertion and [`TransitiveClosure`] is the boolean version.
+//!
+pub mod all_pairs;
+pub mod transitive_closure;
+pub use all_pairs::AllPairs;
+pub use transitive_closure::TransitiveClosure;
+
+use std::ops::Sub;
+use bellman_ford::bellman_ford;
+use dijkstra::dijkstra;
+use graph::{Graph, ShortestPathTree, Weight};
 /// function
-pub fn floyd_warshall(n: usize, e: &[(usize, usize, i64)], is_direct: bool) -> Vec<Vec<i64>> {
-    let mut dist = vec![vec![INF; n]; n];
-    for v in 0..n {
-        dist[v][v] = 0;
+pub fn floyd_warshall<W: Weight>(graph: &Graph<W>) -> Vec<Vec<W>> {
+    let n = graph.n();
+    let mut dist = vec![vec![W::inf(); n]; n];
+    for (v, dist) in dist.iter_mut().enumerate() {
+        dist[v] = W::zero();
     }
-    if is_direct {
-        for &(v, w, a) in e.iter() {
-            dist[v][w] = a;
-        }
-    } else {
-        for &(v, w, a) in e.iter() {
-            dist[v][w] = a;
-            dist[w][v] = a;
-        }
+    for e in graph.arcs().iter() {
+        dist[e.from][e.to] = dist[e.from][e.to].min(e.weight);
     }
     for v in 0..n {
         for u in 0..n {
+            if dist[u][v] == W::inf() {
+                continue;
+            }
             for w in 0..n {
-                dist[u][w] = dist[u][w].min(dist[u][v] + dist[v][w]);
+                dist[u][w] = dist[u][w].min(dist[u][v].saturating_add(dist[v][w]));
             }
         }
     }
     dist
 }
 
-pub fn floyd_warshall_re(n: usize, e: &[(usize, usize, i64)], is_direct: bool) -> Vec<Vec<(i64, Option<usize>)>> {
-    let mut dist = vec![vec![(INF, None); n]; n];
-    for v in 0..n {
-        dist[v][v] = (0, Some(v));
+/// Return the shortest path trees from every vertex. `res[u]` is the tree from `u`.
+pub fn floyd_warshall_re<W: Weight>(graph: &Graph<W>) -> Vec<ShortestPathTree<W>> {
+    let n = graph.n();
+    let mut res = vec![ShortestPathTree::new(n); n];
+    for (v, tree) in res.iter_mut().enumerate() {
+        tree.dist[v] = W::zero();
     }
-    if is_direct {
-        for &(v, w, a) in e.iter() {
-            dist[v][w] = (a, Some(v));
-        }
-    } else {
-        for &(v, w, a) in e.iter() {
-            dist[v][w] = (a, Some(v));
-            dist[w][v] = (a, Some(w));
+    for e in graph.arcs().iter() {
+        if e.weight < res[e.from].dist[e.to] {
+            res[e.from].dist[e.to] = e.weight;
+            res[e.from].parent[e.to] = Some(*e);
         }
     }
     for v in 0..n {
         for u in 0..n {
+            if res[u].dist[v] == W::inf() {
+                continue;
+            }
             for w in 0..n {
-                if dist[u][w].0 > dist[u][v].0 + dist[v][w].0 {
-                    dist[u][w] = (dist[u][v].0 + dist[v][w].0, Some(v));
+                let d = res[u].dist[v].saturating_add(res[v].dist[w]);
+                if d < res[u].dist[w] {
+                    res[u].dist[w] = d;
+                    res[u].parent[w] = res[v].parent[w];
                 }
             }
         }
     }
-    dist
-}
\ No newline at end of file
+    res
+}
+
+/// Johnson's algorithm. O(nm log n)
+/// Return the same distance matrix as [`floyd_warshall`], or `None` if `graph` has a negative cycle.
+pub fn johnson<W: Weight + Sub<Output = W>>(graph: &Graph<W>) -> Option<Vec<Vec<W>>> {
+    let n = graph.n();
+    // potential from a virtual vertex `n` connected to every vertex with weight 0
+    let mut e = graph.arcs().iter()
+        .map(|e| (e.from, e.to, e.weight))
+        .collect::<Vec<_>>();
+    e.extend((0..n).map(|v| (n, v, W::zero())));
+    let potential = bellman_ford(&Graph::directed(n + 1, &e), n);
+    if potential.has_negative_cycle() {
+        return None;
+    }
+    let h = potential.dist;
+    let e = graph.arcs().iter()
+        .map(|e| (e.from, e.to, e.weight.saturating_add(h[e.from]) - h[e.to]))
+        .collect::<Vec<_>>();
+    let reweighted = Graph::directed(n, &e);
+    let res = (0..n)
+        .map(|u| {
+            dijkstra(&reweighted, u).into_iter()
+                .enumerate()
+                .map(|(v, d)| if d == W::inf() { d } else { d.saturating_add(h[v]) - h[u] })
+                .collect()
+        })
+        .collect();
+    Some(res)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn floyd_warshall_re_parent_is_predecessor() {
+        // The shortest path from 0 to 3 goes through 1 and 2, so the parent of 3 is the arc from 2.
+        let graph = Graph::directed(4, &[(0, 1, 1), (1, 2, 1), (2, 3, 1), (0, 3, 10)]);
+        let trees = floyd_warshall_re(&graph);
+        assert_eq!(trees[0].dist, vec![0, 1, 2, 3]);
+        assert_eq!(trees[0].parent[3].map(|e| e.from), Some(2));
+        assert_eq!(trees[0].path_to(3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(trees[0].edge_path_to(3), Some(vec![0, 1, 2]));
+        assert_eq!(trees[1].path_to(3), Some(vec![1, 2, 3]));
+        assert_eq!(trees[3].path_to(0), None);
+    }
+}
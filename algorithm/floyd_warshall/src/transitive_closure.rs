@@ -0,0 +1,62 @@
+//! # Transitive closure
+//!
+//! Define a struct [`TransitiveClosure`] which is the boolean version of Floyd Warshall with bitsets.
+//!
+
+use graph::Graph;
+/// struct of TransitiveClosure
+pub struct TransitiveClosure {
+    is_direct: bool,
+    values: Vec<Vec<u64>>,
+}
+impl TransitiveClosure {
+    /// Constructor of TransitiveClosure of `graph`. O(n^3 / 64)
+    pub fn new<W>(graph: &Graph<W>) -> Self {
+        let n = graph.n();
+        let mut values = vec![vec![0; n.div_ceil(64)]; n];
+        for (v, values) in values.iter_mut().enumerate() {
+            values[v / 64] |= 1 << (v % 64);
+        }
+        for e in graph.arcs().iter() {
+            values[e.from][e.to / 64] |= 1 << (e.to % 64);
+        }
+        for v in 0..n {
+            let row = values[v].clone();
+            for values in values.iter_mut() {
+                if values[v / 64] >> (v % 64) & 1 == 1 {
+                    for (x, y) in values.iter_mut().zip(row.iter()) {
+                        *x |= y;
+                    }
+                }
+            }
+        }
+        Self {
+            is_direct: graph.is_direct(),
+            values,
+        }
+    }
+
+    /// Return if `v` is reachable from `u`.
+    pub fn is_reachable(&self, u: usize, v: usize) -> bool {
+        self.values[u][v / 64] >> (v % 64) & 1 == 1
+    }
+
+    /// Add an edge from `u` to `v` (and from `v` to `u` if undirected). O(n^2 / 64)
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        self.add_arc(u, v);
+        if !self.is_direct {
+            self.add_arc(v, u);
+        }
+    }
+
+    fn add_arc(&mut self, u: usize, v: usize) {
+        let row = self.values[v].clone();
+        for values in self.values.iter_mut() {
+            if values[u / 64] >> (u % 64) & 1 == 1 {
+                for (x, y) in values.iter_mut().zip(row.iter()) {
+                    *x |= y;
+                }
+            }
+        }
+    }
+}
@@ -1,33 +1,27 @@
 //! # Interval sieve
-//! 
+//!
+//! Define a struct [`IntervalSeive`] which is the segmented sieve of a range,
+//! and [`SegmentedPrimes`] which iterates primes with bounded memory.
+//!
 
 use std::ops::RangeBounds;
-/// function
+use eratosthenes::LinearSieve;
+/// struct of the segmented sieve of `l..r`
 pub struct IntervalSeive {
     value: Vec<bool>,
     min_value: usize,
+    // primes up to `sqrt(r)`
+    primes: Vec<usize>,
 }
 impl IntervalSeive {
-    /// Constructor
+    /// Constructor of the sieve of `range`. O(sqrt(r) + (r - l) log log r)
     pub fn new<R: RangeBounds<usize>>(range: R) -> IntervalSeive {
         let (l, r) = open(range);
-        let mut value = vec![true; r - l];
-        let mut small = (0..)
-            .take_while(|i| i * i <= r)
-            .map(|_| true).collect::<Vec<_>>();
-        for i in (2..).take_while(|i| i * i <= r) {
-            if small[i] {
-                for j in (2..).take_while(|j| j * j <= r) {
-                    small[j] = false;
-                }
-                for j in 2.max((l + i - 1) / i * i)..=r {
-                    value[j - l] = false;
-                }
-            }
-        }
+        let primes = LinearSieve::new(r.isqrt()).primes().to_vec();
         Self {
-            value,
+            value: sieve(l, r, &primes),
             min_value: l,
+            primes,
         }
     }
 
@@ -35,7 +29,133 @@ impl IntervalSeive {
     pub fn is_prime(&self, x: usize) -> bool {
         self.value[x - self.min_value]
     }
+
+    /// Return the primes in the range in ascending order.
+    pub fn primes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.value.iter()
+            .enumerate()
+            .filter(|&(_, &b)| b)
+            .map(|(i, _)| self.min_value + i)
+    }
+
+    /// Return the prime factorizations of all numbers in the range. `res[x - l]` is that of `x`,
+    /// and that of 0 is empty. O((r - l) log r)
+    pub fn prime_factorize_all(&self) -> Vec<Vec<(usize, usize)>> {
+        let l = self.min_value;
+        let mut rest = (l..l + self.value.len()).collect::<Vec<_>>();
+        let mut res = vec![vec![]; rest.len()];
+        for &p in self.primes.iter() {
+            for x in (l.div_ceil(p).max(1) * p..l + rest.len()).step_by(p) {
+                let mut exp = 0;
+                while rest[x - l] % p == 0 {
+                    rest[x - l] /= p;
+                    exp += 1;
+                }
+                res[x - l].push((p, exp));
+            }
+        }
+        // The rest is 1 or a prime larger than `sqrt(r)`.
+        for (factors, &rest) in res.iter_mut().zip(rest.iter()) {
+            if rest > 1 {
+                factors.push((rest, 1));
+            }
+        }
+        res
+    }
+}
+
+/// struct of the iterator of primes in a range, sieving one segment of odd numbers at a time.
+/// It uses O(sqrt(r) + segment) memory.
+pub struct SegmentedPrimes {
+    // odd primes up to `sqrt(r)`
+    primes: Vec<usize>,
+    // `segment[i]` is if `start + 2i + 1` is prime, where `start` is even.
+    segment: Vec<bool>,
+    start: usize,
+    // the next index in `segment`, and the start of the next segment
+    index: usize,
+    next: usize,
+    end: usize,
+    size: usize,
+    // if 2 is not returned yet
+    two: bool,
+}
+impl SegmentedPrimes {
+    /// Constructor of the iterator of primes in `range`.
+    pub fn new<R: RangeBounds<usize>>(range: R) -> SegmentedPrimes {
+        let (l, r) = open(range);
+        let primes = LinearSieve::new(r.isqrt()).primes().iter().skip(1).copied().collect();
+        let size = (1 << 18).max(r.isqrt() + 1).next_multiple_of(2);
+        Self {
+            primes,
+            segment: vec![],
+            start: l - l % 2,
+            index: 0,
+            next: l - l % 2,
+            end: r,
+            size,
+            two: l <= 2 && 2 < r,
+        }
+    }
 }
+impl Iterator for SegmentedPrimes {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.two {
+            self.two = false;
+            return Some(2);
+        }
+        loop {
+            while self.index < self.segment.len() {
+                self.index += 1;
+                if self.segment[self.index - 1] {
+                    return Some(self.start + 2 * self.index - 1);
+                }
+            }
+            if self.next >= self.end {
+                return None;
+            }
+            self.start = self.next;
+            self.next = self.end.min(self.start + self.size);
+            self.segment = odd_sieve(self.start, self.next, &self.primes);
+            self.index = 0;
+        }
+    }
+}
+
+// Return if each odd number of `l..r` is prime, where `l` is even and `primes` contains all odd primes up to `sqrt(r)`.
+fn odd_sieve(l: usize, r: usize, primes: &[usize]) -> Vec<bool> {
+    let mut value = vec![true; (r - l) / 2];
+    if l == 0 && !value.is_empty() {
+        value[0] = false;
+    }
+    for &p in primes.iter() {
+        // the first odd multiple of `p` which is at least `max(p^2, l)`
+        let mut x = l.div_ceil(p).max(p) * p;
+        if x % 2 == 0 {
+            x += p;
+        }
+        for x in (x..r).step_by(2 * p) {
+            value[(x - l) / 2] = false;
+        }
+    }
+    value
+}
+
+// Return if each number of `l..r` is prime, where `primes` contains all primes up to `sqrt(r)`.
+fn sieve(l: usize, r: usize, primes: &[usize]) -> Vec<bool> {
+    let mut value = vec![true; r.saturating_sub(l)];
+    for x in l..r.min(2) {
+        value[x - l] = false;
+    }
+    for &p in primes.iter() {
+        for x in (l.div_ceil(p).max(p) * p..r).step_by(p) {
+            value[x - l] = false;
+        }
+    }
+    value
+}
+
 fn open<R: RangeBounds<usize>>(range: R) -> (usize, usize) {
     use std::ops::Bound;
     let start = match range.start_bound() {
@@ -49,4 +169,4 @@ fn open<R: RangeBounds<usize>>(range: R) -> (usize, usize) {
         Bound::Excluded(&x) => x,
     };
     (start, end)
-}
\ No newline at end of file
+}
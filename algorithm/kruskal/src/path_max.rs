@@ -0,0 +1,110 @@
+//! # Path max on MST
+//!
+//! Define a struct [`MstPathMax`] which answers the maximum edge on the path of a minimum spanning forest
+//! by binary lifting, and the MST cost when an edge is forced in.
+//!
+
+use std::ops::Sub;
+use graph::{Graph, Mst, Weight};
+/// struct of MstPathMax
+pub struct MstPathMax<W> {
+    cost: W,
+    edges: Vec<(usize, usize, W)>,
+    is_tree: Vec<bool>,
+    depth: Vec<usize>,
+    /// `ancestor[k][v]` is the `2^k`-th ancestor of `v` and the maximum weight on the way.
+    ancestor: Vec<Vec<(usize, W)>>,
+}
+impl<W: Weight> MstPathMax<W> {
+    /// Constructor of MstPathMax from `graph` and its minimum spanning forest `mst`. O(n log n + m)
+    pub fn new(graph: &Graph<W>, mst: &Mst<W>) -> Self {
+        let n = graph.n();
+        let tree = mst.tree();
+        let mut depth = vec![usize::MAX; n];
+        let mut parent = (0..n).map(|v| (v, W::zero())).collect::<Vec<_>>();
+        for root in 0..n {
+            if depth[root] != usize::MAX {
+                continue;
+            }
+            depth[root] = 0;
+            let mut stack = vec![root];
+            while let Some(v) = stack.pop() {
+                for e in tree[v].iter() {
+                    if depth[e.to] == usize::MAX {
+                        depth[e.to] = depth[v] + 1;
+                        parent[e.to] = (v, e.weight);
+                        stack.push(e.to);
+                    }
+                }
+            }
+        }
+        let mut ancestor = vec![parent];
+        while 1 << ancestor.len() < n {
+            let last = ancestor.last().unwrap();
+            let next = last.iter()
+                .map(|&(v, a)| (last[v].0, a.max(last[v].1)))
+                .collect();
+            ancestor.push(next);
+        }
+        let mut is_tree = vec![false; graph.m()];
+        for e in mst.edges.iter() {
+            is_tree[e.id] = true;
+        }
+        Self {
+            cost: mst.cost,
+            edges: graph.edges().iter().map(|e| (e.from, e.to, e.weight)).collect(),
+            is_tree,
+            depth,
+            ancestor,
+        }
+    }
+
+    /// Return the maximum weight on the path between `u` and `v`. O(log n)
+    /// Return `None` if `u == v` or they are not connected.
+    pub fn path_max(&self, mut u: usize, mut v: usize) -> Option<W> {
+        if self.depth[u] < self.depth[v] {
+            std::mem::swap(&mut u, &mut v);
+        }
+        let mut res = None;
+        for (k, ancestor) in self.ancestor.iter().enumerate().rev() {
+            if (self.depth[u] - self.depth[v]) >> k & 1 == 1 {
+                res = res.max(Some(ancestor[u].1));
+                u = ancestor[u].0;
+            }
+        }
+        for ancestor in self.ancestor.iter().rev() {
+            if ancestor[u].0 != ancestor[v].0 {
+                res = res.max(Some(ancestor[u].1)).max(Some(ancestor[v].1));
+                u = ancestor[u].0;
+                v = ancestor[v].0;
+            }
+        }
+        if u != v {
+            if self.ancestor[0][u].0 != self.ancestor[0][v].0 {
+                return None;
+            }
+            res = res.max(Some(self.ancestor[0][u].1)).max(Some(self.ancestor[0][v].1));
+        }
+        res
+    }
+}
+impl<W: Weight + Sub<Output = W>> MstPathMax<W> {
+    /// Return the cost of the minimum spanning forest containing the edge `id`. O(log n)
+    /// Return `None` if the edge is a self-loop.
+    pub fn forced_cost(&self, id: usize) -> Option<W> {
+        if self.is_tree[id] {
+            return Some(self.cost);
+        }
+        let (u, v, w) = self.edges[id];
+        Some(self.cost.saturating_add(w) - self.path_max(u, v)?)
+    }
+
+    /// Return the cost of the second minimum spanning forest, which differs from the MST by one edge. O(m log n)
+    /// It may equal to the MST cost. Return `None` if there is no other spanning forest.
+    pub fn second_best(&self) -> Option<W> {
+        (0..self.edges.len())
+            .filter(|&id| !self.is_tree[id])
+            .filter_map(|id| self.forced_cost(id))
+            .min()
+    }
+}
@@ -0,0 +1,101 @@
+//! # Manhattan MST
+//!
+//! Minimum spanning tree of points under L1 distance by the octant sweep,
+//! and under L2 distance by [`dense_prim`] for small n.
+//!
+use graph::{Graph, Mst, Total};
+use kruskal::kruskal;
+use prim::dense_prim;
+use segtree::{Monoid, Segtree};
+/// Return at most 4n candidate edges `(i, j, distance)` which contain a Manhattan MST. O(n log n)
+pub fn manhattan_mst_edges(points: &[(i64, i64)]) -> Vec<(usize, usize, i64)> {
+    let n = points.len();
+    let mut points = points.to_vec();
+    let mut res = vec![];
+    for dir in 0..4 {
+        if dir % 2 == 1 {
+            for p in points.iter_mut() {
+                *p = (p.1, p.0);
+            }
+        } else if dir == 2 {
+            for p in points.iter_mut() {
+                p.0 = -p.0;
+            }
+        }
+        let mut order = (0..n).collect::<Vec<_>>();
+        order.sort_by_key(|&i| points[i]);
+        let mut keys = points.iter().map(|&(x, y)| y - x).collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+        // For each point, find the nearest point in the octant `x' >= x, y' - x' >= y - x`,
+        // which minimizes `x' + y'`.
+        let mut segtree = Segtree::<MinIndex>::new(&vec![MinIndex::id(); keys.len()]);
+        for &i in order.iter().rev() {
+            let (x, y) = points[i];
+            let position = keys.binary_search(&(y - x)).unwrap();
+            let (d, j) = segtree.fold(position..);
+            if j != usize::MAX {
+                res.push((i, j, d - x - y));
+            }
+            if (x + y, i) < segtree[position] {
+                segtree.update_at(position, (x + y, i));
+            }
+        }
+    }
+    res
+}
+
+/// Return a minimum spanning tree of points under L1 distance. O(n log n)
+/// The ids of the edges refer to [`manhattan_mst_edges`].
+pub fn manhattan_mst(points: &[(i64, i64)]) -> Mst<i64> {
+    kruskal(&Graph::undirected(points.len(), &manhattan_mst_edges(points)))
+}
+
+/// Return a minimum spanning tree of points under L2 distance. O(n^2)
+pub fn euclidean_mst(points: &[(f64, f64)]) -> Mst<Total> {
+    dense_prim(points.len(), |i, j| {
+        Total((points[i].0 - points[j].0).hypot(points[i].1 - points[j].1))
+    })
+}
+
+/// Monoid of minimum `(x + y, index)`
+enum MinIndex {}
+impl Monoid for MinIndex {
+    type Value = (i64, usize);
+    fn id() -> Self::Value {
+        (i64::MAX, usize::MAX)
+    }
+    fn op(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value {
+        *lhs.min(rhs)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn complete_mst_cost(points: &[(i64, i64)]) -> i64 {
+        let mut e = vec![];
+        for (i, &(x, y)) in points.iter().enumerate() {
+            for (j, &(z, w)) in points.iter().enumerate().skip(i + 1) {
+                e.push((i, j, (x - z).abs() + (y - w).abs()));
+            }
+        }
+        kruskal(&Graph::undirected(points.len(), &e)).cost
+    }
+
+    #[test]
+    fn manhattan_mst_matches_complete_graph() {
+        // duplicated points, points on the diagonals and ties of distance in every octant
+        let cases: [&[(i64, i64)]; 4] = [
+            &[(0, 0), (0, 0), (1, 1), (-1, 1), (1, -1), (-1, -1), (2, 0), (0, 2), (-2, 0), (0, -2)],
+            &[(3, 1), (1, 3), (3, 1), (-3, -1), (-1, -3), (5, 5), (-5, 5), (2, -7), (0, 0)],
+            &[(0, 0), (1, 0), (2, 0), (3, 0), (0, 1), (1, 1), (2, 1), (3, 1), (1, 0)],
+            &[(7, -2), (-4, 6), (0, 9), (-8, -8), (5, 5), (-4, 6), (1, -9), (3, 3)],
+        ];
+        for points in cases {
+            let mst = manhattan_mst(points);
+            assert!(mst.is_connected());
+            assert_eq!(mst.cost, complete_mst_cost(points));
+        }
+    }
+}
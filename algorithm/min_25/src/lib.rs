@@ -0,0 +1,65 @@
+//! # Min_25 sieve
+//!
+//! [`min_25`] returns the sum of a multiplicative function from its sums over primes,
+//! which are given by `prime_count::lucy_hedgehog` for instance.
+//! [`dirichlet_hyperbola`] returns the sum of a Dirichlet convolution.
+//! `T` is a ring such as `GaloisField<P>`.
+//!
+use std::ops::{Add, Mul, Sub};
+use eratosthenes::LinearSieve;
+/// Min_25 sieve. O(n^{3/4} / log n)
+/// Return the sum of `f(i)` for `1 <= i <= n` of the multiplicative function `f`, where `f(p^k) = prime_power(p, k, p^k)`,
+/// and `prime_sum(v)` is the sum of `f(p)` over primes `p <= v`, called for `v = n / k` or `v <= sqrt(n)`.
+pub fn min_25<T, F, G>(n: u64, mut prime_sum: G, mut prime_power: F) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<u8>,
+    F: FnMut(u64, usize, u64) -> T,
+    G: FnMut(u64) -> T,
+{
+    if n == 0 {
+        return T::from(0u8);
+    }
+    let primes = LinearSieve::new(n.isqrt() as usize).primes().iter().map(|&p| p as u64).collect::<Vec<_>>();
+    T::from(1u8) + sum(n, 0, &primes, &mut prime_sum, &mut prime_power)
+}
+
+// Return the sum of `f(i)` for `2 <= i <= v` whose least prime factor is at least `primes[j]`.
+fn sum<T, F, G>(v: u64, j: usize, primes: &[u64], prime_sum: &mut G, prime_power: &mut F) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<u8>,
+    F: FnMut(u64, usize, u64) -> T,
+    G: FnMut(u64) -> T,
+{
+    // primes. `v` is at least `primes[j - 1]`.
+    let smaller = if j == 0 { T::from(0u8) } else { prime_sum(primes[j - 1]) };
+    let mut res = prime_sum(v) - smaller;
+    // composites `p^e * m` with `p` the least prime factor
+    for (k, &p) in primes.iter().enumerate().skip(j).take_while(|&(_, &p)| p * p <= v) {
+        let (mut pe, mut e) = (p, 1);
+        while pe * p <= v {
+            res = res + prime_power(p, e, pe) * sum(v / pe, k + 1, primes, prime_sum, prime_power) + prime_power(p, e + 1, pe * p);
+            pe *= p;
+            e += 1;
+        }
+    }
+    res
+}
+
+/// Dirichlet hyperbola method. O(sqrt(n)) calls
+/// Return the sum of `(f * g)(i)` for `1 <= i <= n`, where `*` is the Dirichlet convolution,
+/// and `f_sum(v)` and `g_sum(v)` are the sums of `f(i)` and `g(i)` for `1 <= i <= v`.
+pub fn dirichlet_hyperbola<T, F, G, FS, GS>(n: u64, mut f: F, mut g: G, mut f_sum: FS, mut g_sum: GS) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<u8>,
+    F: FnMut(u64) -> T,
+    G: FnMut(u64) -> T,
+    FS: FnMut(u64) -> T,
+    GS: FnMut(u64) -> T,
+{
+    let s = n.isqrt();
+    let mut res = T::from(0u8);
+    for i in 1..=s {
+        res = res + f(i) * g_sum(n / i) + g(i) * f_sum(n / i);
+    }
+    res - f_sum(s) * g_sum(s)
+}
@@ -1,30 +1,60 @@
 //! # Prim
-//! 
-/// function
-pub fn prim(n: usize, e: &[(usize, usize, i64)]) -> i64 {
-    let _e = e;
-    let mut e = vec![vec![]; n];
-    for &(v, w, a) in _e.iter() {
-        e[v].push((w, a));
-        e[w].push((v, a));
-    }
-    let mut cost = 0;
-    let mut heap = std::collections::BinaryHeap::new();
-    let mut is_used = vec![false; n];
-    for &(w, a) in e[0].iter() {
-        heap.push((!a, w));
-        is_used[0] = true;
-    }
-    while let Some((d, v)) = heap.pop() {
-        let d = !d;
-        if is_used[v] {
+//!
+//! [`dense_prim`] is the O(n^2) version for complete graphs given by a cost function.
+//!
+use std::{cmp::Reverse, collections::BinaryHeap};
+use graph::{Edge, Graph, Mst, Weight};
+/// function. `graph` should be undirected. Return the minimum spanning forest if disconnected.
+pub fn prim<W: Weight>(graph: &Graph<W>) -> Mst<W> {
+    let mut mst = Mst::new(graph.n());
+    let mut heap = BinaryHeap::new();
+    let mut is_used = vec![false; graph.n()];
+    for root in 0..graph.n() {
+        if is_used[root] {
             continue;
         }
-        cost += d;
+        is_used[root] = true;
+        for e in graph[root].iter() {
+            heap.push((Reverse(e.weight), e.to, e.from, e.id));
+        }
+        while let Some((Reverse(weight), to, from, id)) = heap.pop() {
+            if is_used[to] {
+                continue;
+            }
+            mst.push(Edge { from, to, weight, id });
+            is_used[to] = true;
+            for e in graph[to].iter() {
+                if !is_used[e.to] {
+                    heap.push((Reverse(e.weight), e.to, e.from, e.id));
+                }
+            }
+        }
+    }
+    mst
+}
+
+/// Prim for the complete graph whose cost of edge `(v, w)` is `cost(v, w)`. O(n^2)
+/// Edges of cost `W::inf()` are ignored. The id of a chosen edge is its index in `edges`.
+pub fn dense_prim<W: Weight, F: FnMut(usize, usize) -> W>(n: usize, mut cost: F) -> Mst<W> {
+    let mut mst = Mst::new(n);
+    let mut is_used = vec![false; n];
+    // the cheapest edge `(cost, from)` from the used vertices
+    let mut min = vec![(W::inf(), n); n];
+    for _ in 0..n {
+        let v = (0..n).filter(|&v| !is_used[v]).min_by_key(|&v| min[v].0).unwrap();
         is_used[v] = true;
-        for &(w, a) in e[v].iter() {
-            heap.push((!a, w));
+        if min[v].0 < W::inf() {
+            let id = mst.edges.len();
+            mst.push(Edge { from: min[v].1, to: v, weight: min[v].0, id });
+        }
+        for w in 0..n {
+            if !is_used[w] {
+                let c = cost(v, w);
+                if c < min[w].0 {
+                    min[w] = (c, v);
+                }
+            }
         }
     }
-    cost
-}
\ No newline at end of file
+    mst
+}
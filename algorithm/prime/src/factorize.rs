@@ -0,0 +1,98 @@
+//! # Factorize
+//!
+//! Pollard's rho for `u64`, and [`divisors`] and [`euler_phi`] built on it.
+//!
+use euclid::euclid;
+use crate::{is_prime, mul_mod};
+
+/// Return prime factorization as `(prime, exponent)` in ascending order of prime. O(x^{1/4} log x) expected
+pub fn factorize(x: u64) -> Vec<(u64, usize)> {
+    let mut primes = vec![];
+    let mut stack = vec![x];
+    while let Some(x) = stack.pop() {
+        if x < 2 {
+            continue;
+        }
+        if is_prime(x) {
+            primes.push(x);
+            continue;
+        }
+        let d = find_factor(x);
+        stack.push(d);
+        stack.push(x / d);
+    }
+    primes.sort_unstable();
+    let mut res: Vec<(u64, usize)> = vec![];
+    for p in primes {
+        match res.last_mut() {
+            Some((q, exp)) if *q == p => *exp += 1,
+            _ => res.push((p, 1)),
+        }
+    }
+    res
+}
+
+/// Return the set of divisors in ascending order.
+pub fn divisors(x: u64) -> Vec<u64> {
+    let mut res = vec![1];
+    for (p, exp) in factorize(x) {
+        for i in 0..res.len() {
+            let mut v = res[i];
+            for _ in 0..exp {
+                v *= p;
+                res.push(v);
+            }
+        }
+    }
+    res.sort_unstable();
+    res
+}
+
+/// Return Euler's totient function of `x`.
+pub fn euler_phi(x: u64) -> u64 {
+    factorize(x).iter().fold(x, |phi, &(p, _)| phi / p * (p - 1))
+}
+
+// Return a nontrivial factor of the composite number `x`, by Brent's variant of Pollard's rho.
+fn find_factor(x: u64) -> u64 {
+    if x.is_multiple_of(2) {
+        return 2;
+    }
+    let f = |y: u64, c: u64| ((y as u128 * y as u128 + c as u128) % x as u128) as u64;
+    // the number of steps multiplied before taking gcd
+    const M: usize = 128;
+    for c in 1.. {
+        let (mut y, mut g, mut q) = (2, 1, 1);
+        let (mut saved, mut z) = (y, y);
+        let mut r = 1;
+        while g == 1 {
+            z = y;
+            for _ in 0..r {
+                y = f(y, c);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                saved = y;
+                for _ in 0..M.min(r - k) {
+                    y = f(y, c);
+                    q = mul_mod(q, z.abs_diff(y), x);
+                }
+                g = euclid(q, x);
+                k += M;
+            }
+            r *= 2;
+        }
+        if g == x {
+            // Retry one by one from the last saved point.
+            g = 1;
+            while g == 1 {
+                saved = f(saved, c);
+                g = euclid(z.abs_diff(saved), x);
+            }
+        }
+        if g != x {
+            return g;
+        }
+    }
+    unreachable!()
+}
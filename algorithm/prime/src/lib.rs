@@ -1,16 +1,75 @@
 //! # Prime
 //!
-//! 
-//! 
-/// function
+//! [`is_prime`] is the deterministic Miller-Rabin test for all `u64`,
+//! and [`factorize`] is Pollard's rho on top of it.
+//!
+pub mod factorize;
+pub use factorize::{divisors, euler_phi, factorize};
+
+/// Deterministic Miller-Rabin. O(log x)
 pub fn is_prime(x: u64) -> bool {
-    if x == 0 || x == 1 {
+    if x < 2 {
         return false;
     }
-    for i in (2..).take_while(|i| i * i <= x) {
-        if x % i == 0 {
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if x.is_multiple_of(p) {
+            return x == p;
+        }
+    }
+    let s = (x - 1).trailing_zeros();
+    let d = (x - 1) >> s;
+    // These bases are enough for all `x < 2^64`.
+    for a in [2, 325, 9375, 28178, 450775, 9780504, 1795265022] {
+        let mut y = pow_mod(a % x, d, x);
+        if y == 0 || y == 1 || y == x - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            y = mul_mod(y, y, x);
+            if y == x - 1 {
+                break;
+            }
+        }
+        if y != x - 1 {
             return false;
         }
     }
     true
-}
\ No newline at end of file
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut res = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = mul_mod(res, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    res
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strong_pseudoprimes_and_large_inputs() {
+        // strong pseudoprimes to several small bases
+        assert!(!is_prime(3215031751));
+        assert_eq!(factorize(3215031751), vec![(151, 1), (751, 1), (28351, 1)]);
+        assert!(!is_prime(3825123056546413051));
+        assert_eq!(factorize(3825123056546413051), vec![(149491, 1), (747451, 1), (34233211, 1)]);
+        // the largest prime below 2^64
+        assert!(is_prime(18446744073709551557));
+        assert_eq!(factorize(18446744073709551557), vec![(18446744073709551557, 1)]);
+        assert_eq!(
+            factorize(u64::MAX),
+            vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)],
+        );
+        assert_eq!(factorize(4294967291 * 4294967279), vec![(4294967279, 1), (4294967291, 1)]);
+    }
+}
@@ -0,0 +1,66 @@
+//! # Prime count
+//!
+//! Define a struct [`PrimeSum`] which is the result of [`lucy_hedgehog`],
+//! the sums of a completely multiplicative function over primes up to every `n / k`.
+//!
+use std::ops::{Add, Mul, Sub};
+use eratosthenes::LinearSieve;
+/// struct of the sums over primes up to every `n / k`
+#[derive(Clone, Debug)]
+pub struct PrimeSum<T> {
+    n: u64,
+    // `small[v]` is the sum up to `v`, and `large[k]` is the sum up to `n / k`.
+    small: Vec<T>,
+    large: Vec<T>,
+}
+impl<T: Copy> PrimeSum<T> {
+    /// Return the upper bound `n`.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Return the sum of `f(p)` over primes `p <= v`. `v` must be `n / k` for some `k`, or at most `sqrt(n)`.
+    pub fn get(&self, v: u64) -> T {
+        if v < self.small.len() as u64 {
+            self.small[v as usize]
+        } else {
+            self.large[(self.n / v) as usize]
+        }
+    }
+}
+
+/// Lucy_Hedgehog's algorithm. O(n^{3/4} / log n)
+/// `f` is completely multiplicative, and `prefix(v)` is the sum of `f(i)` for `2 <= i <= v`.
+pub fn lucy_hedgehog<T, F, G>(n: u64, mut f: F, mut prefix: G) -> PrimeSum<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    F: FnMut(u64) -> T,
+    G: FnMut(u64) -> T,
+{
+    let r = n.isqrt();
+    let mut small = (0..=r).map(&mut prefix).collect::<Vec<_>>();
+    let mut large = (0..=r).map(|k| prefix(n.checked_div(k).unwrap_or(0))).collect::<Vec<_>>();
+    for &p in LinearSieve::new(r as usize).primes() {
+        let p = p as u64;
+        let (fp, sp, p2) = (f(p), small[p as usize - 1], p * p);
+        // Remove the numbers whose least prime factor is `p`.
+        for k in (1..=r).take_while(|&k| n / k >= p2) {
+            let next = if k * p <= r { large[(k * p) as usize] } else { small[(n / (k * p)) as usize] };
+            large[k as usize] = large[k as usize] - fp * (next - sp);
+        }
+        for v in (p2..=r).rev() {
+            small[v as usize] = small[v as usize] - fp * (small[(v / p) as usize] - sp);
+        }
+    }
+    PrimeSum { n, small, large }
+}
+
+/// Return the number of primes up to `n`. O(n^{3/4} / log n)
+pub fn prime_pi(n: u64) -> u64 {
+    lucy_hedgehog(n, |_| 1, |v| v.saturating_sub(1)).get(n)
+}
+
+/// Return the sum of primes up to `n`. O(n^{3/4} / log n)
+pub fn prime_sum(n: u64) -> u128 {
+    lucy_hedgehog(n, |p| p as u128, |v| (v as u128 * (v as u128 + 1) / 2).saturating_sub(1)).get(n)
+}
@@ -0,0 +1,141 @@
+//!
+//! # Graph
+//!
+//! Define a struct [`Graph`] which is a CSR (compressed sparse row) representation
+//! of a weighted graph, and a struct [`Edge`].
+//! The trait [`Weight`] of edge weights is defined in [`weight`],
+//! the result [`ShortestPathTree`] of shortest path algorithms in [`shortest_path_tree`],
+//! and the result [`Mst`] of minimum spanning tree algorithms in [`mst`].
+//!
+pub mod mst;
+pub mod shortest_path_tree;
+pub mod weight;
+pub use mst::Mst;
+pub use shortest_path_tree::ShortestPathTree;
+pub use weight::{Total, Weight};
+
+use std::ops::Index;
+/// struct of edge (or arc) of Graph
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Edge<W> {
+    /// tail of the edge
+    pub from: usize,
+    /// head of the edge
+    pub to: usize,
+    /// weight of the edge
+    pub weight: W,
+    /// index of the edge in the input list
+    pub id: usize,
+}
+
+/// struct of Graph
+#[derive(Clone)]
+pub struct Graph<W> {
+    n: usize,
+    is_direct: bool,
+    edges: Vec<Edge<W>>,
+    start: Vec<usize>,
+    arcs: Vec<Edge<W>>,
+    reverse_start: Vec<usize>,
+    reverse_arcs: Vec<Edge<W>>,
+}
+impl<W: Copy> Graph<W> {
+    /// Constructor of Graph with `n` vertices and edges `e = [(from, to, weight)]`. O(n + m)
+    pub fn new(n: usize, e: &[(usize, usize, W)], is_direct: bool) -> Self {
+        let edges = e.iter()
+            .enumerate()
+            .map(|(id, &(from, to, weight))| Edge { from, to, weight, id })
+            .collect::<Vec<_>>();
+        let mut arcs = edges.clone();
+        if !is_direct {
+            arcs.extend(edges.iter().map(|e| Edge { from: e.to, to: e.from, ..*e }));
+        }
+        let reverse_arcs = arcs.iter()
+            .map(|e| Edge { from: e.to, to: e.from, ..*e })
+            .collect::<Vec<_>>();
+        let (start, arcs) = csr(n, arcs);
+        let (reverse_start, reverse_arcs) = csr(n, reverse_arcs);
+        // reverse adjacency keeps the orientation `from -> to` of the original arcs.
+        let reverse_arcs = reverse_arcs.into_iter()
+            .map(|e| Edge { from: e.to, to: e.from, ..e })
+            .collect();
+        Self {
+            n,
+            is_direct,
+            edges,
+            start,
+            arcs,
+            reverse_start,
+            reverse_arcs,
+        }
+    }
+
+    /// Constructor of directed Graph.
+    pub fn directed(n: usize, e: &[(usize, usize, W)]) -> Self {
+        Self::new(n, e, true)
+    }
+
+    /// Constructor of undirected Graph.
+    pub fn undirected(n: usize, e: &[(usize, usize, W)]) -> Self {
+        Self::new(n, e, false)
+    }
+}
+impl<W> Graph<W> {
+    /// Return the number of vertices.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Return the number of edges in the input list.
+    pub fn m(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Return if self is directed.
+    pub fn is_direct(&self) -> bool {
+        self.is_direct
+    }
+
+    /// Return the edges in the input order. `edges()[id].id == id`
+    pub fn edges(&self) -> &[Edge<W>] {
+        &self.edges
+    }
+
+    /// Return all arcs sorted by `from`.
+    /// Each undirected edge appears twice, once for each direction.
+    pub fn arcs(&self) -> &[Edge<W>] {
+        &self.arcs
+    }
+
+    /// Return the arcs going out of `v`. Every arc satisfies `from == v`.
+    pub fn adjacent(&self, v: usize) -> &[Edge<W>] {
+        &self.arcs[self.start[v]..self.start[v + 1]]
+    }
+
+    /// Return the arcs coming into `v`. Every arc satisfies `to == v`.
+    pub fn reverse(&self, v: usize) -> &[Edge<W>] {
+        &self.reverse_arcs[self.reverse_start[v]..self.reverse_start[v + 1]]
+    }
+}
+impl<W> Index<usize> for Graph<W> {
+    type Output = [Edge<W>];
+    fn index(&self, index: usize) -> &Self::Output {
+        self.adjacent(index)
+    }
+}
+fn csr<W: Copy>(n: usize, arcs: Vec<Edge<W>>) -> (Vec<usize>, Vec<Edge<W>>) {
+    let mut start = vec![0; n + 1];
+    for e in arcs.iter() {
+        start[e.from + 1] += 1;
+    }
+    for v in 0..n {
+        start[v + 1] += start[v];
+    }
+    let mut position = start.clone();
+    let mut sorted = arcs.clone();
+    for e in arcs {
+        sorted[position[e.from]] = e;
+        position[e.from] += 1;
+    }
+    (start, sorted)
+}
@@ -0,0 +1,58 @@
+//! # Minimum spanning tree
+//!
+//! Define a struct [`Mst`] which is the result of minimum spanning tree (forest) algorithms.
+//!
+
+use crate::{Edge, Graph, Weight};
+/// struct of minimum spanning forest
+#[derive(Clone, Debug)]
+pub struct Mst<W> {
+    n: usize,
+    /// total cost of the chosen edges
+    pub cost: W,
+    /// chosen edges. `id` is the index in the input graph.
+    pub edges: Vec<Edge<W>>,
+}
+impl<W: Weight> Mst<W> {
+    /// Constructor of Mst of `n` vertices without edges.
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            cost: W::zero(),
+            edges: vec![],
+        }
+    }
+
+    /// Add an edge to self.
+    pub fn push(&mut self, e: Edge<W>) {
+        self.cost = self.cost.saturating_add(e.weight);
+        self.edges.push(e);
+    }
+
+    /// Return the number of vertices.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Return the number of connected components.
+    pub fn components(&self) -> usize {
+        self.n - self.edges.len()
+    }
+
+    /// Return if the graph is connected, i.e. self is a spanning tree.
+    pub fn is_connected(&self) -> bool {
+        self.components() <= 1
+    }
+
+    /// Return the ids of the chosen edges.
+    pub fn edge_ids(&self) -> Vec<usize> {
+        self.edges.iter().map(|e| e.id).collect()
+    }
+
+    /// Return the tree (forest if disconnected) as an undirected graph.
+    /// The id of an edge of the tree is its index in `edges`, not in the input graph.
+    pub fn tree(&self) -> Graph<W> {
+        let e = self.edges.iter().map(|e| (e.from, e.to, e.weight)).collect::<Vec<_>>();
+        Graph::undirected(self.n, &e)
+    }
+}
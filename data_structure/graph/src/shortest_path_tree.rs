@@ -0,0 +1,96 @@
+//! # Shortest path tree
+//!
+//! Define a struct [`ShortestPathTree`] which is the result of shortest path algorithms.
+//!
+
+use crate::{Edge, Weight};
+/// struct of shortest path tree (or forest for multiple sources)
+#[derive(Clone, Debug)]
+pub struct ShortestPathTree<W> {
+    /// distance from the source. `W::inf()` if unreachable.
+    pub dist: Vec<W>,
+    /// the last arc of the shortest path to each vertex. `None` for sources and unreachable vertices.
+    pub parent: Vec<Option<Edge<W>>>,
+}
+impl<W: Weight> ShortestPathTree<W> {
+    /// Constructor of ShortestPathTree with `n` unreachable vertices.
+    pub fn new(n: usize) -> Self {
+        Self {
+            dist: vec![W::inf(); n],
+            parent: vec![None; n],
+        }
+    }
+
+    /// Return if `v` is reachable.
+    pub fn is_reachable(&self, v: usize) -> bool {
+        self.dist[v] < W::inf()
+    }
+
+    /// Return the vertices of the path from the source to `target`. O(length)
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        let arcs = self.arc_path_to(target)?;
+        let mut res = Vec::with_capacity(arcs.len() + 1);
+        res.push(arcs.first().map_or(target, |e| e.from));
+        res.extend(arcs.iter().map(|e| e.to));
+        Some(res)
+    }
+
+    /// Return the edge ids of the path from the source to `target`. O(length)
+    pub fn edge_path_to(&self, target: usize) -> Option<Vec<usize>> {
+        Some(self.arc_path_to(target)?.iter().map(|e| e.id).collect())
+    }
+
+    /// Return the arcs of the path from the source to `target`. O(length)
+    /// Return `None` if `target` is unreachable or the parents form a cycle.
+    pub fn arc_path_to(&self, target: usize) -> Option<Vec<Edge<W>>> {
+        if !self.is_reachable(target) {
+            return None;
+        }
+        let mut res = vec![];
+        let mut v = target;
+        while let Some(e) = self.parent[v] {
+            if res.len() == self.dist.len() {
+                return None;
+            }
+            res.push(e);
+            v = e.from;
+        }
+        res.reverse();
+        Some(res)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+
+    #[test]
+    fn path_follows_parents() {
+        // Edges 1 and 2 are used against their input direction.
+        let graph = Graph::undirected(5, &[(0, 1, 2), (2, 1, 3), (3, 2, 1)]);
+        let arc = |v: usize, w: usize| *graph[v].iter().find(|e| e.to == w).unwrap();
+        let mut tree = ShortestPathTree::new(5);
+        tree.dist = vec![0, 2, 5, 6, u64::inf()];
+        tree.parent = vec![None, Some(arc(0, 1)), Some(arc(1, 2)), Some(arc(2, 3)), None];
+        assert_eq!(tree.path_to(3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(tree.edge_path_to(3), Some(vec![0, 1, 2]));
+        let arcs = tree.arc_path_to(3).unwrap();
+        assert_eq!(arcs.iter().map(|e| (e.from, e.to)).collect::<Vec<_>>(), vec![(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(tree.path_to(0), Some(vec![0]));
+        assert_eq!(tree.edge_path_to(0), Some(vec![]));
+        assert!(!tree.is_reachable(4));
+        assert_eq!(tree.path_to(4), None);
+        assert_eq!(tree.edge_path_to(4), None);
+    }
+
+    #[test]
+    fn parent_cycle_gives_none() {
+        let graph = Graph::directed(3, &[(1, 2, 1), (2, 1, 1)]);
+        let mut tree = ShortestPathTree::new(3);
+        tree.dist = vec![0, 1, 2];
+        tree.parent = vec![None, Some(graph[2][0]), Some(graph[1][0])];
+        assert_eq!(tree.arc_path_to(1), None);
+        assert_eq!(tree.path_to(2), None);
+        assert_eq!(tree.edge_path_to(2), None);
+    }
+}
@@ -0,0 +1,115 @@
+//! # Weight
+//!
+//! Define a trait [`Weight`] of edge weights and a struct [`Total`] which is `f64` with total order.
+//!
+
+use std::{cmp::Ordering, ops::{Add, Sub}};
+/// trait of edge weight for shortest path algorithms
+pub trait Weight: Copy + Ord {
+    /// Return the weight of the empty path
+    fn zero() -> Self;
+    /// Return the infinity, which is the distance of unreachable vertices
+    fn inf() -> Self;
+    /// Return `self + rhs`. Return `inf()` if either is `inf()` or the sum overflows.
+    fn saturating_add(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_weight_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Weight for $t {
+                fn zero() -> Self {
+                    0
+                }
+                fn inf() -> Self {
+                    <$t>::MAX
+                }
+                fn saturating_add(self, rhs: Self) -> Self {
+                    if self == Self::inf() || rhs == Self::inf() {
+                        Self::inf()
+                    } else {
+                        <$t>::saturating_add(self, rhs)
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_weight_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Lexicographic weight such as `(cost, hops)`.
+impl<A: Weight, B: Weight> Weight for (A, B) {
+    fn zero() -> Self {
+        (A::zero(), B::zero())
+    }
+    fn inf() -> Self {
+        (A::inf(), B::inf())
+    }
+    fn saturating_add(self, rhs: Self) -> Self {
+        // Overflow of either component makes the whole sum `inf()`.
+        let (a, b) = (self.0.saturating_add(rhs.0), self.1.saturating_add(rhs.1));
+        if a == A::inf() || b == B::inf() {
+            Self::inf()
+        } else {
+            (a, b)
+        }
+    }
+}
+
+/// struct of `f64` which is ordered by [`f64::total_cmp`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Total(pub f64);
+impl PartialEq for Total {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Total {}
+impl PartialOrd for Total {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Total {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+impl Add for Total {
+    type Output = Total;
+    fn add(self, rhs: Self) -> Self::Output {
+        Total(self.0 + rhs.0)
+    }
+}
+impl Sub for Total {
+    type Output = Total;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Total(self.0 - rhs.0)
+    }
+}
+impl Weight for Total {
+    fn zero() -> Self {
+        Total(0.0)
+    }
+    fn inf() -> Self {
+        Total(f64::INFINITY)
+    }
+    fn saturating_add(self, rhs: Self) -> Self {
+        if self == Self::inf() || rhs == Self::inf() {
+            Self::inf()
+        } else {
+            self + rhs
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tuple_saturating_add_overflow_is_inf() {
+        assert_eq!((u64::MAX - 1, 0).saturating_add((5, 1)), <(u64, u64)>::inf());
+        assert_eq!((0, u64::MAX - 1).saturating_add((1, 5)), <(u64, u64)>::inf());
+        assert_eq!((3, 1).saturating_add((4, 1)), (7, 2));
+    }
+}
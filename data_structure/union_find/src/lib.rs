@@ -1,19 +1,57 @@
 //!
 //! # UnionFind
 //! 
-//! Define a struct [`UnionFind`]
+//! Define a struct [`UnionFind`] and trait [`Merge`] for the values of sets.
 //! 
+//! [`WeightedUnionFind`] also maintains differences of potentials.
+//! [`RollbackUnionFind`] can undo unions, which [`OfflineDynamicConnectivity`] is built on,
+//! and every version of [`PersistentUnionFind`] stays accessible.
+//! [`PartiallyPersistentUnionFind`] answers queries on past states such as when two points got connected.
 //! 
+pub mod merge;
+pub mod offline_dynamic_connectivity;
+pub mod partially_persistent_union_find;
+pub mod persistent_union_find;
+pub mod rollback_union_find;
+pub mod weighted_union_find;
+pub use offline_dynamic_connectivity::OfflineDynamicConnectivity;
+pub use partially_persistent_union_find::PartiallyPersistentUnionFind;
+pub use persistent_union_find::PersistentUnionFind;
+pub use rollback_union_find::RollbackUnionFind;
+pub use weighted_union_find::WeightedUnionFind;
+
+/// trait of the values of sets, merged on `unite`
+pub trait Merge {
+    /// the value of each set
+    type Value;
+    /// Merge `rhs` into `lhs`.
+    fn merge(lhs: &mut Self::Value, rhs: Self::Value);
+}
+impl Merge for () {
+    type Value = ();
+    fn merge(_: &mut Self::Value, _: Self::Value) {}
+}
+
 /// struct of UnionFind
-pub struct UnionFind {
+pub struct UnionFind<M: Merge = ()> {
     values: Vec<isize>,
+    // the value of each set, held by its root
+    data: Vec<Option<M::Value>>,
     count: usize,
 }
 impl UnionFind {
     /// Constructor of `UnionFind` with all points isolated. 
     pub fn new(n: usize) -> Self {
+        Self::with_values(vec![(); n])
+    }
+}
+impl<M: Merge> UnionFind<M> {
+    /// Constructor of `UnionFind` with all points isolated, and `values[v]` is the value of `{v}`.
+    pub fn with_values(values: Vec<M::Value>) -> Self {
+        let n = values.len();
         Self {
             values: vec![-1; n],
+            data: values.into_iter().map(Some).collect(),
             count : n,
         }
     }
@@ -32,18 +70,33 @@ impl UnionFind {
         }
         self.values[v] += self.values[w];
         self.values[w] = v as isize;
+        let value = self.data[w].take().unwrap();
+        M::merge(self.data[v].as_mut().unwrap(), value);
         self.count -= 1;
         true
     }
 
+    /// Return the value of the set of v.
+    pub fn value(&mut self, v: usize) -> &M::Value {
+        let r = self.root(v);
+        self.data[r].as_ref().unwrap()
+    }
+
+    /// Return the mutable value of the set of v.
+    pub fn value_mut(&mut self, v: usize) -> &mut M::Value {
+        let r = self.root(v);
+        self.data[r].as_mut().unwrap()
+    }
+
     /// Return is same between v and w
-    pub fn is_same(&self, v: usize, w: usize) -> bool {
+    pub fn is_same(&mut self, v: usize, w: usize) -> bool {
         self.root(v) == self.root(w)
     }
 
     /// Return the size of set of v.
-    pub fn size(&self, v: usize) -> usize {
-        - self.values[self.root(v)] as usize
+    pub fn size(&mut self, v: usize) -> usize {
+        let r = self.root(v);
+        - self.values[r] as usize
     }
 
     /// Return how many sets are.
@@ -51,10 +104,39 @@ impl UnionFind {
         self.count
     }
 
-    fn root(&self, mut v: usize) -> usize {
+    /// Return the representative of the set of v.
+    pub fn leader(&mut self, v: usize) -> usize {
+        self.root(v)
+    }
+
+    /// Return all sets. Each set is sorted, and sets are sorted by their smallest element.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let n = self.values.len();
+        let mut index = vec![usize::MAX; n];
+        let mut res: Vec<Vec<usize>> = vec![];
+        for v in 0..n {
+            let r = self.root(v);
+            if index[r] == usize::MAX {
+                index[r] = res.len();
+                res.push(vec![]);
+            }
+            res[index[r]].push(v);
+        }
+        res
+    }
+
+    fn root(&mut self, v: usize) -> usize {
+        let mut r = v;
+        while self.values[r] >= 0 {
+            r = self.values[r] as usize;
+        }
+        // path compression
+        let mut v = v;
         while self.values[v] >= 0 {
-            v = self.values[v] as usize;
+            let next = self.values[v] as usize;
+            self.values[v] = r as isize;
+            v = next;
         }
-        v
+        r
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,57 @@
+//! # Trait samples of merge
+//!
+//! Define enums [`Sum`], [`Min`], [`Max`], [`Members`] and [`SortedValues`] for implement `Merge` trait.
+//!
+use std::collections::BTreeSet;
+use super::*;
+
+/// enum for the sum of the values of a set
+pub enum Sum {}
+impl Merge for Sum {
+    type Value = i64;
+    fn merge(lhs: &mut Self::Value, rhs: Self::Value) {
+        *lhs += rhs;
+    }
+}
+
+/// enum for the minimum of the values of a set
+pub enum Min {}
+impl Merge for Min {
+    type Value = i64;
+    fn merge(lhs: &mut Self::Value, rhs: Self::Value) {
+        *lhs = (*lhs).min(rhs);
+    }
+}
+
+/// enum for the maximum of the values of a set
+pub enum Max {}
+impl Merge for Max {
+    type Value = i64;
+    fn merge(lhs: &mut Self::Value, rhs: Self::Value) {
+        *lhs = (*lhs).max(rhs);
+    }
+}
+
+/// enum for the members of a set. The initial value of `v` should be `vec![v]`. O(n log n) in total.
+pub enum Members {}
+impl Merge for Members {
+    type Value = Vec<usize>;
+    fn merge(lhs: &mut Self::Value, mut rhs: Self::Value) {
+        if lhs.len() < rhs.len() {
+            std::mem::swap(lhs, &mut rhs);
+        }
+        lhs.extend(rhs);
+    }
+}
+
+/// enum for the set of the values of a set, merged small to large. O(n log^2 n) in total.
+pub enum SortedValues {}
+impl Merge for SortedValues {
+    type Value = BTreeSet<i64>;
+    fn merge(lhs: &mut Self::Value, mut rhs: Self::Value) {
+        if lhs.len() < rhs.len() {
+            std::mem::swap(lhs, &mut rhs);
+        }
+        lhs.extend(rhs);
+    }
+}
@@ -0,0 +1,100 @@
+//! # Offline dynamic connectivity
+//!
+//! Define a struct [`OfflineDynamicConnectivity`] which answers connectivity queries under edge insertions
+//! and deletions, by putting each edge on a segment tree over time and traversing it with [`RollbackUnionFind`].
+//!
+use std::collections::HashMap;
+use crate::RollbackUnionFind;
+/// struct of offline dynamic connectivity
+pub struct OfflineDynamicConnectivity {
+    n: usize,
+    queries: usize,
+    // the times when each edge currently alive was added
+    alive: HashMap<(usize, usize), Vec<usize>>,
+    // `(u, v, l, r)`: the edge is alive for the queries `l..r`
+    edges: Vec<(usize, usize, usize, usize)>,
+}
+impl OfflineDynamicConnectivity {
+    /// Constructor of `OfflineDynamicConnectivity` of the graph with `n` vertices and no edges.
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            queries: 0,
+            alive: HashMap::new(),
+            edges: vec![],
+        }
+    }
+
+    /// Add an undirected edge `(u, v)`. Parallel edges are allowed.
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        self.alive.entry((u.min(v), u.max(v))).or_default().push(self.queries);
+    }
+
+    /// Remove an edge `(u, v)` added before.
+    pub fn remove_edge(&mut self, u: usize, v: usize) {
+        let (u, v) = (u.min(v), u.max(v));
+        let l = self.alive.get_mut(&(u, v)).and_then(|times| times.pop()).expect("edge does not exist");
+        self.edges.push((u, v, l, self.queries));
+    }
+
+    /// Add a query on the current graph and return its index.
+    pub fn query(&mut self) -> usize {
+        self.queries += 1;
+        self.queries - 1
+    }
+
+    /// Call `f(i, union_find)` for each query `i` in order, where `union_find` represents the graph at the query. O(n + q + m log q log n)
+    /// where `m` is the number of calls of `add_edge` and `remove_edge`.
+    pub fn run<F: FnMut(usize, &RollbackUnionFind)>(&self, mut f: F) {
+        let q = self.queries;
+        if q == 0 {
+            return;
+        }
+        let mut segments = vec![vec![]; 4 * q];
+        let rest = self.alive.iter()
+            .flat_map(|(&(u, v), times)| times.iter().map(move |&l| (u, v, l, q)));
+        for (u, v, l, r) in self.edges.iter().copied().chain(rest) {
+            if l < r {
+                insert(&mut segments, 1, 0, q, l, r, (u, v));
+            }
+        }
+        let mut union_find = RollbackUnionFind::new(self.n);
+        dfs(&segments, 1, 0, q, &mut union_find, &mut f);
+    }
+}
+
+// Put `edge` on the nodes covering `l..r` in the subtree of `node` covering `lo..hi`.
+fn insert(segments: &mut [Vec<(usize, usize)>], node: usize, lo: usize, hi: usize, l: usize, r: usize, edge: (usize, usize)) {
+    if r <= lo || hi <= l {
+        return;
+    }
+    if l <= lo && hi <= r {
+        segments[node].push(edge);
+        return;
+    }
+    let mid = (lo + hi) / 2;
+    insert(segments, node * 2, lo, mid, l, r, edge);
+    insert(segments, node * 2 + 1, mid, hi, l, r, edge);
+}
+
+fn dfs<F: FnMut(usize, &RollbackUnionFind)>(
+    segments: &[Vec<(usize, usize)>],
+    node: usize,
+    lo: usize,
+    hi: usize,
+    union_find: &mut RollbackUnionFind,
+    f: &mut F,
+) {
+    let snapshot = union_find.snapshot();
+    for &(u, v) in segments[node].iter() {
+        union_find.unite(u, v);
+    }
+    if hi - lo == 1 {
+        f(lo, union_find);
+    } else {
+        let mid = (lo + hi) / 2;
+        dfs(segments, node * 2, lo, mid, union_find, f);
+        dfs(segments, node * 2 + 1, mid, hi, union_find, f);
+    }
+    union_find.rollback(snapshot);
+}
@@ -0,0 +1,88 @@
+//! # Partially persistent UnionFind
+//!
+//! Define a struct [`PartiallyPersistentUnionFind`] which answers queries on past states.
+//! The time is the number of calls of `unite`, so time `t` is the state after the first `t` calls.
+//! Each query is O(log n).
+//!
+/// struct of partially persistent UnionFind
+pub struct PartiallyPersistentUnionFind {
+    parent: Vec<usize>,
+    // the time when `v` was attached to `parent[v]`, `usize::MAX` if `v` is a root
+    time: Vec<usize>,
+    // `(time, size)` of the set whose root is `v`, in ascending order of time
+    sizes: Vec<Vec<(usize, usize)>>,
+    now: usize,
+}
+impl PartiallyPersistentUnionFind {
+    /// Constructor of `PartiallyPersistentUnionFind` with all points isolated at time 0.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            time: vec![usize::MAX; n],
+            sizes: vec![vec![(0, 1)]; n],
+            now: 0,
+        }
+    }
+
+    /// Unite `v` and `w` and advance the time by 1, return true if v and w is not connected and be connect.
+    pub fn unite(&mut self, v: usize, w: usize) -> bool {
+        self.now += 1;
+        let mut v = self.root(v, self.now);
+        let mut w = self.root(w, self.now);
+        if v == w {
+            return false;
+        }
+        let (sv, sw) = (self.sizes[v].last().unwrap().1, self.sizes[w].last().unwrap().1);
+        if sv < sw {
+            std::mem::swap(&mut v, &mut w);
+        }
+        self.parent[w] = v;
+        self.time[w] = self.now;
+        self.sizes[v].push((self.now, sv + sw));
+        true
+    }
+
+    /// Return the current time.
+    pub fn now(&self) -> usize {
+        self.now
+    }
+
+    /// Return is same between v and w at time `t`.
+    pub fn is_same(&self, v: usize, w: usize, t: usize) -> bool {
+        self.root(v, t) == self.root(w, t)
+    }
+
+    /// Return the size of set of v at time `t`.
+    pub fn size(&self, v: usize, t: usize) -> usize {
+        let sizes = &self.sizes[self.root(v, t)];
+        sizes[sizes.partition_point(|&(s, _)| s <= t) - 1].1
+    }
+
+    /// Return the representative of the set of v at time `t`.
+    pub fn leader(&self, v: usize, t: usize) -> usize {
+        self.root(v, t)
+    }
+
+    /// Return the earliest time when `v` and `w` are connected, or `None` if they are not connected now.
+    pub fn when_connected(&self, mut v: usize, mut w: usize) -> Option<usize> {
+        let mut res = 0;
+        while v != w {
+            if self.time[v] > self.time[w] {
+                std::mem::swap(&mut v, &mut w);
+            }
+            if self.time[v] == usize::MAX {
+                return None;
+            }
+            res = self.time[v];
+            v = self.parent[v];
+        }
+        Some(res)
+    }
+
+    fn root(&self, mut v: usize, t: usize) -> usize {
+        while self.time[v] <= t {
+            v = self.parent[v];
+        }
+        v
+    }
+}
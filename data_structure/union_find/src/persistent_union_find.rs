@@ -0,0 +1,118 @@
+//! # Persistent UnionFind
+//!
+//! Define a struct [`PersistentUnionFind`] whose every version stays accessible.
+//! Versions are numbered from 0, the initial state. Each operation is O(log^2 n).
+//!
+/// struct of fully persistent UnionFind
+pub struct PersistentUnionFind {
+    // persistent array of the values of `UnionFind` as a binary tree of depth `depth`
+    nodes: Vec<Node>,
+    depth: usize,
+    // `(root node, count)` of each version
+    versions: Vec<(usize, usize)>,
+}
+#[derive(Clone, Copy)]
+struct Node {
+    children: [usize; 2],
+    value: isize,
+}
+impl PersistentUnionFind {
+    /// Constructor of `PersistentUnionFind` whose version 0 has all points isolated.
+    pub fn new(n: usize) -> Self {
+        let mut depth = 0;
+        while 1 << depth < n {
+            depth += 1;
+        }
+        // all leaves of the initial tree are shared
+        let mut nodes = vec![Node { children: [0, 0], value: -1 }];
+        for i in 0..depth {
+            nodes.push(Node { children: [i, i], value: 0 });
+        }
+        Self {
+            nodes,
+            depth,
+            versions: vec![(depth, n)],
+        }
+    }
+
+    /// Unite `v` and `w` in `version` and return the new version.
+    /// The new version is the same as `version` if `v` and `w` are already connected.
+    pub fn unite(&mut self, version: usize, v: usize, w: usize) -> usize {
+        let (root, count) = self.versions[version];
+        let mut v = self.root(version, v);
+        let mut w = self.root(version, w);
+        if v == w {
+            self.versions.push((root, count));
+            return self.versions.len() - 1;
+        }
+        let (mut sv, mut sw) = (self.get(root, v), self.get(root, w));
+        if sv > sw {
+            std::mem::swap(&mut v, &mut w);
+            std::mem::swap(&mut sv, &mut sw);
+        }
+        let root = self.set(root, v, sv + sw);
+        let root = self.set(root, w, v as isize);
+        self.versions.push((root, count - 1));
+        self.versions.len() - 1
+    }
+
+    /// Return how many versions are.
+    pub fn versions(&self) -> usize {
+        self.versions.len()
+    }
+
+    /// Return is same between v and w in `version`.
+    pub fn is_same(&self, version: usize, v: usize, w: usize) -> bool {
+        self.root(version, v) == self.root(version, w)
+    }
+
+    /// Return the size of set of v in `version`.
+    pub fn size(&self, version: usize, v: usize) -> usize {
+        - self.get(self.versions[version].0, self.root(version, v)) as usize
+    }
+
+    /// Return how many sets are in `version`.
+    pub fn count(&self, version: usize) -> usize {
+        self.versions[version].1
+    }
+
+    /// Return the representative of the set of v in `version`.
+    pub fn leader(&self, version: usize, v: usize) -> usize {
+        self.root(version, v)
+    }
+
+    fn root(&self, version: usize, mut v: usize) -> usize {
+        let root = self.versions[version].0;
+        loop {
+            let value = self.get(root, v);
+            if value < 0 {
+                return v;
+            }
+            v = value as usize;
+        }
+    }
+
+    fn get(&self, mut node: usize, i: usize) -> isize {
+        for d in (0..self.depth).rev() {
+            node = self.nodes[node].children[i >> d & 1];
+        }
+        self.nodes[node].value
+    }
+
+    // Return the root of a new tree whose `i`-th value is `value`.
+    fn set(&mut self, node: usize, i: usize, value: isize) -> usize {
+        let mut path = vec![node];
+        for d in (0..self.depth).rev() {
+            path.push(self.nodes[*path.last().unwrap()].children[i >> d & 1]);
+        }
+        let mut child = Node { children: [0, 0], value };
+        for (d, &node) in path.iter().rev().skip(1).enumerate() {
+            self.nodes.push(child);
+            let mut parent = self.nodes[node];
+            parent.children[i >> d & 1] = self.nodes.len() - 1;
+            child = parent;
+        }
+        self.nodes.push(child);
+        self.nodes.len() - 1
+    }
+}
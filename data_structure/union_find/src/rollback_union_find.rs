@@ -0,0 +1,93 @@
+//! # Rollback UnionFind
+//!
+//! Define a struct [`RollbackUnionFind`] which can undo unions.
+//! It uses union by size without path compression, so each operation is O(log n).
+//!
+/// struct of UnionFind with rollback
+#[derive(Clone)]
+pub struct RollbackUnionFind {
+    values: Vec<isize>,
+    count: usize,
+    // `(v, w, values[w])` for each `unite` which attached `w` under `v`, `None` if it did nothing.
+    history: Vec<Option<(usize, usize, isize)>>,
+}
+impl RollbackUnionFind {
+    /// Constructor of `RollbackUnionFind` with all points isolated.
+    pub fn new(n: usize) -> Self {
+        Self {
+            values: vec![-1; n],
+            count: n,
+            history: vec![],
+        }
+    }
+
+    /// Unite `v` and `w`, return true if v and w is not connected and be connect.
+    pub fn unite(&mut self, v: usize, w: usize) -> bool {
+        let mut v = self.root(v);
+        let mut w = self.root(w);
+        if v == w {
+            self.history.push(None);
+            return false;
+        }
+        if self.values[v] > self.values[w] {
+            std::mem::swap(&mut v, &mut w);
+        }
+        self.history.push(Some((v, w, self.values[w])));
+        self.values[v] += self.values[w];
+        self.values[w] = v as isize;
+        self.count -= 1;
+        true
+    }
+
+    /// Undo the last `unite`, including one which did nothing. Return false if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(last) = self.history.pop() else {
+            return false;
+        };
+        if let Some((v, w, value)) = last {
+            self.values[w] = value;
+            self.values[v] -= value;
+            self.count += 1;
+        }
+        true
+    }
+
+    /// Return the current state to pass to [`rollback`](Self::rollback).
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undo all `unite` after `snapshot` was taken.
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+
+    /// Return is same between v and w
+    pub fn is_same(&self, v: usize, w: usize) -> bool {
+        self.root(v) == self.root(w)
+    }
+
+    /// Return the size of set of v.
+    pub fn size(&self, v: usize) -> usize {
+        - self.values[self.root(v)] as usize
+    }
+
+    /// Return how many sets are.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Return the representative of the set of v.
+    pub fn leader(&self, v: usize) -> usize {
+        self.root(v)
+    }
+
+    fn root(&self, mut v: usize) -> usize {
+        while self.values[v] >= 0 {
+            v = self.values[v] as usize;
+        }
+        v
+    }
+}
@@ -0,0 +1,90 @@
+//! # Weighted UnionFind
+//!
+//! Define a struct [`WeightedUnionFind`] which maintains differences of potentials over an [`AbelianGroup`].
+//!
+use fenwick_tree::AbelianGroup;
+/// struct of UnionFind with potentials
+pub struct WeightedUnionFind<G: AbelianGroup> {
+    values: Vec<isize>,
+    // `weight(v) - weight(parent of v)`
+    diffs: Vec<G::Value>,
+    count: usize,
+}
+impl<G: AbelianGroup> WeightedUnionFind<G>
+where
+    G::Value: Clone + PartialEq,
+{
+    /// Constructor of `WeightedUnionFind` with all points isolated.
+    pub fn new(n: usize) -> Self {
+        Self {
+            values: vec![-1; n],
+            diffs: vec![G::id(); n],
+            count: n,
+        }
+    }
+
+    /// Add the constraint `weight(v) - weight(u) = w`.
+    /// Return false if it contradicts the constraints so far, in which case nothing changes.
+    pub fn unite(&mut self, u: usize, v: usize, w: G::Value) -> bool {
+        let (mut u, wu) = self.root(u);
+        let (mut v, wv) = self.root(v);
+        // weight(v) - weight(u) for the roots
+        let mut w = G::op(&G::op(&wu, &w), &G::inv(&wv));
+        if u == v {
+            return w == G::id();
+        }
+        if self.values[u] > self.values[v] {
+            std::mem::swap(&mut u, &mut v);
+            w = G::inv(&w);
+        }
+        self.values[u] += self.values[v];
+        self.values[v] = u as isize;
+        self.diffs[v] = w;
+        self.count -= 1;
+        true
+    }
+
+    /// Return `weight(v) - weight(u)`, or `None` if `u` and `v` are not connected.
+    pub fn diff(&mut self, u: usize, v: usize) -> Option<G::Value> {
+        let (ru, wu) = self.root(u);
+        let (rv, wv) = self.root(v);
+        if ru == rv {
+            Some(G::op(&wv, &G::inv(&wu)))
+        } else {
+            None
+        }
+    }
+
+    /// Return is same between v and w
+    pub fn is_same(&mut self, v: usize, w: usize) -> bool {
+        self.root(v).0 == self.root(w).0
+    }
+
+    /// Return the size of set of v.
+    pub fn size(&mut self, v: usize) -> usize {
+        let r = self.root(v).0;
+        - self.values[r] as usize
+    }
+
+    /// Return how many sets are.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Return the representative of the set of v.
+    pub fn leader(&mut self, v: usize) -> usize {
+        self.root(v).0
+    }
+
+    // Return the root and `weight(v) - weight(root)`.
+    fn root(&mut self, v: usize) -> (usize, G::Value) {
+        if self.values[v] < 0 {
+            return (v, G::id());
+        }
+        let parent = self.values[v] as usize;
+        let (r, w) = self.root(parent);
+        self.diffs[v] = G::op(&w, &self.diffs[v]);
+        self.values[v] = r as isize;
+        (r, self.diffs[v].clone())
+    }
+}